@@ -1,8 +1,13 @@
 from . import graphing
 from .core.edge import (
     are_neighbor_cells,
+    cells_boundary,
+    cells_outer_ring,
+    cells_perimeter_edges,
     cells_to_directed_edge,
     directed_edge_to_boundary,
+    directed_edge_to_boundary_wkb,
+    directed_edge_to_boundary_wkt,
     directed_edge_to_cells,
     get_directed_edge_destination,
     get_directed_edge_origin,
@@ -10,13 +15,23 @@ from .core.edge import (
     origin_to_directed_edges,
 )
 from .core.indexing import (
+    bbox_to_cells,
+    cell_contains_latlng,
     cell_to_boundary,
+    cell_to_boundary_wkb,
+    cell_to_boundary_wkt,
     cell_to_lat,
     cell_to_latlng,
     cell_to_lng,
     cell_to_local_ij,
+    cells_to_geojson,
+    cells_to_multi_polygon_wkt,
+    cells_to_multi_polygon_wkt_agg,
+    circle_to_cells,
     latlng_to_cell,
     local_ij_to_cell,
+    point_to_cell,
+    polygon_to_cells,
 )
 from .core.inspection import (
     cell_to_center_child,
@@ -45,30 +60,59 @@ from .core.metrics import (
     great_circle_distance,
 )
 from .core.traversal import (
+    cells_connected_components,
+    cells_dilate,
+    cells_erode,
+    getis_ord_gi_star,
     grid_disk,
+    grid_disk_distances,
     grid_distance,
+    grid_distance_matrix,
+    grid_path_avoiding,
     grid_path_cells,
     grid_ring,
+    kring_smooth,
+    linestring_to_cells,
 )
 from .core.vertexes import (
     cell_to_vertex,
     cell_to_vertexes,
     is_valid_vertex,
     vertex_to_latlng,
+    vertex_to_latlng_wkb,
 )
 
 __all__ = [
     "grid_distance",
+    "grid_distance_matrix",
     "grid_ring",
     "grid_disk",
+    "grid_disk_distances",
     "grid_path_cells",
+    "grid_path_avoiding",
+    "cells_connected_components",
+    "cells_dilate",
+    "cells_erode",
+    "kring_smooth",
+    "getis_ord_gi_star",
+    "linestring_to_cells",
     "latlng_to_cell",
+    "point_to_cell",
     "cell_to_lat",
     "cell_to_lng",
     "cell_to_latlng",
     "cell_to_local_ij",
     "local_ij_to_cell",
     "cell_to_boundary",
+    "cell_to_boundary_wkt",
+    "cell_to_boundary_wkb",
+    "polygon_to_cells",
+    "bbox_to_cells",
+    "circle_to_cells",
+    "cell_contains_latlng",
+    "cells_to_multi_polygon_wkt",
+    "cells_to_multi_polygon_wkt_agg",
+    "cells_to_geojson",
     "get_resolution",
     "str_to_int",
     "int_to_str",
@@ -87,6 +131,7 @@ __all__ = [
     "cell_to_vertex",
     "cell_to_vertexes",
     "vertex_to_latlng",
+    "vertex_to_latlng_wkb",
     "is_valid_vertex",
     "are_neighbor_cells",
     "cells_to_directed_edge",
@@ -95,7 +140,12 @@ __all__ = [
     "get_directed_edge_destination",
     "directed_edge_to_cells",
     "origin_to_directed_edges",
+    "cells_boundary",
+    "cells_outer_ring",
+    "cells_perimeter_edges",
     "directed_edge_to_boundary",
+    "directed_edge_to_boundary_wkt",
+    "directed_edge_to_boundary_wkb",
     "great_circle_distance",
     "average_hexagon_area",
     "get_pentagons",
@@ -3,3 +3,12 @@ from typing import Literal, Union
 HexResolution = Union[
     Literal[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15], int
 ]
+
+CoordinateOutput = Literal["list", "geoarrow"]
+PointOutput = Literal["list", "struct", "geoarrow"]
+AntimeridianMode = Literal["ignore", "split", "unwrap"]
+AngleUnit = Literal["deg", "rad"]
+DiskOutput = Literal["struct", "rings"]
+RingStrictness = Literal["raise", "null"]
+DistanceMatrixOutput = Literal["matrix", "long"]
+SmoothingKernel = Literal["uniform", "inverse_distance", "gaussian"]
@@ -6,6 +6,8 @@ from typing import TYPE_CHECKING
 import polars as pl
 from polars.plugins import register_plugin_function
 
+from ._types import AngleUnit, AntimeridianMode
+
 if TYPE_CHECKING:
     from polars_h3.typing import IntoExprColumn
 
@@ -285,7 +287,11 @@ def origin_to_directed_edges(cell: IntoExprColumn) -> pl.Expr:
     )
 
 
-def directed_edge_to_boundary(edge: IntoExprColumn) -> pl.Expr:
+def directed_edge_to_boundary(
+    edge: IntoExprColumn,
+    antimeridian: AntimeridianMode = "ignore",
+    unit: AngleUnit = "deg",
+) -> pl.Expr:
     """
     Retrieve the geographic boundary (list of lat/lng pairs) defining a directed edge.
 
@@ -294,10 +300,18 @@ def directed_edge_to_boundary(edge: IntoExprColumn) -> pl.Expr:
     #### Parameters
     - `edge`: IntoExprColumn
         Column or expression with the H3 directed edge index.
+    - `antimeridian`: str
+        How edges crossing ±180° longitude are returned:
+        - `"ignore"`: longitudes stay in `[-180, 180]` (default)
+        - `"split"`: edges are split at the antimeridian into several parts
+        - `"unwrap"`: longitudes continue past ±180° so the line stays continuous
+    - `unit`: str
+        Unit of the returned coordinates, `"deg"` (default) or `"rad"`.
 
     #### Returns
     Expr
-        Expression returning a list of lat/lng pairs representing the polygonal boundary of the edge.
+        Expression returning a list of lat/lng pairs representing the boundary of the edge.
+        With `antimeridian="split"`, each row is a list of such parts.
 
     #### Examples
     ```python
@@ -320,4 +334,219 @@ def directed_edge_to_boundary(edge: IntoExprColumn) -> pl.Expr:
         args=[edge],
         plugin_path=LIB,
         function_name="directed_edge_to_boundary",
+        kwargs={"antimeridian": antimeridian, "unit": unit},
+    )
+
+
+def directed_edge_to_boundary_wkt(
+    edge: IntoExprColumn, antimeridian: AntimeridianMode = "ignore"
+) -> pl.Expr:
+    """
+    Retrieve the geographic boundary of a directed edge as a WKT `LINESTRING`.
+
+    Coordinates are in lng/lat order, as expected by GIS tools.
+
+    #### Parameters
+    - `edge`: IntoExprColumn
+        Column or expression with the H3 directed edge index.
+    - `antimeridian`: str
+        How edges crossing ±180° longitude are returned:
+        - `"ignore"`: longitudes stay in `[-180, 180]` (default)
+        - `"split"`: edges are split at the antimeridian into a `MULTILINESTRING`
+        - `"unwrap"`: longitudes continue past ±180° so the line stays continuous
+
+    #### Returns
+    Expr
+        Expression returning the edge boundary as a WKT string.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"edge": [1608492358964346879]})
+    >>> df.with_columns(boundary=polars_h3.directed_edge_to_boundary_wkt("edge"))
+    shape: (1, 2)
+    ┌─────────────────────┬───────────────────────────────────┐
+    │ edge                │ boundary                          │
+    │ ---                 │ ---                               │
+    │ u64                 │ str                               │
+    ╞═════════════════════╪═══════════════════════════════════╡
+    │ 1608492358964346879 │ LINESTRING(-121.91508032705622 3… │
+    └─────────────────────┴───────────────────────────────────┘
+    ```
+    """
+    return register_plugin_function(
+        args=[edge],
+        plugin_path=LIB,
+        function_name="directed_edge_to_boundary_wkt",
+        is_elementwise=True,
+        kwargs={"antimeridian": antimeridian},
+    )
+
+
+def directed_edge_to_boundary_wkb(
+    edge: IntoExprColumn, antimeridian: AntimeridianMode = "ignore"
+) -> pl.Expr:
+    """
+    Retrieve the geographic boundary of a directed edge as a WKB `LineString`.
+
+    The geometry is encoded as little-endian ISO WKB with lng/lat coordinates.
+
+    #### Parameters
+    - `edge`: IntoExprColumn
+        Column or expression with the H3 directed edge index.
+    - `antimeridian`: str
+        How edges crossing ±180° longitude are returned:
+        - `"ignore"`: longitudes stay in `[-180, 180]` (default)
+        - `"split"`: edges are split at the antimeridian into a `MultiLineString`
+        - `"unwrap"`: longitudes continue past ±180° so the line stays continuous
+
+    #### Returns
+    Expr
+        Expression returning the edge boundary as `pl.Binary`.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"edge": [1608492358964346879]})
+    >>> df.with_columns(boundary=polars_h3.directed_edge_to_boundary_wkb("edge"))
+    shape: (1, 2)
+    ┌─────────────────────┬───────────────────────────────────┐
+    │ edge                │ boundary                          │
+    │ ---                 │ ---                               │
+    │ u64                 │ binary                            │
+    ╞═════════════════════╪═══════════════════════════════════╡
+    │ 1608492358964346879 │ b"\x01\x02\x00\x00\x00\x02\x00… │
+    └─────────────────────┴───────────────────────────────────┘
+    ```
+    """
+    return register_plugin_function(
+        args=[edge],
+        plugin_path=LIB,
+        function_name="directed_edge_to_boundary_wkb",
+        is_elementwise=True,
+        kwargs={"antimeridian": antimeridian},
+    )
+
+
+def cells_boundary(cells: IntoExprColumn) -> pl.Expr:
+    """
+    Find the cells of a set that have at least one neighbor outside the set.
+
+    Returns the cells on the edge of each row's cell set, i.e. those with at least one
+    directed edge leading out of the set, in order of first appearance. Useful for
+    region outlines.
+
+    Null or invalid cells are ignored, and all cells in a set should share a
+    resolution.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        `List` column of H3 cells (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+
+    #### Returns
+    Expr
+        Expression returning a list of H3 cells per row, or `None` for null lists.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"cell": ["8928308280fffff"]})
+    >>> df = df.select(cells=polars_h3.grid_disk("cell", 1))
+    >>> df.select(polars_h3.cells_boundary("cells").list.len())
+    shape: (1, 1)
+    ┌───────┐
+    │ cells │
+    │ ---   │
+    │ u32   │
+    ╞═══════╡
+    │ 6     │
+    └───────┘
+    ```
+    """
+    return register_plugin_function(
+        args=[cells],
+        plugin_path=LIB,
+        function_name="cells_boundary",
+        is_elementwise=True,
+    )
+
+
+def cells_outer_ring(cells: IntoExprColumn) -> pl.Expr:
+    """
+    Find the cells just outside a set that neighbor it.
+
+    Returns the distinct neighbors of each row's cell set that are not in the set
+    themselves, in order of first appearance. Useful as a one-cell buffer around a
+    region.
+
+    Null or invalid cells are ignored, and all cells in a set should share a
+    resolution.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        `List` column of H3 cells (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+
+    #### Returns
+    Expr
+        Expression returning a list of H3 cells per row, or `None` for null lists.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"cell": ["8928308280fffff"]})
+    >>> df = df.select(cells=polars_h3.grid_disk("cell", 1))
+    >>> df.select(polars_h3.cells_outer_ring("cells").list.len())
+    shape: (1, 1)
+    ┌───────┐
+    │ cells │
+    │ ---   │
+    │ u32   │
+    ╞═══════╡
+    │ 12    │
+    └───────┘
+    ```
+    """
+    return register_plugin_function(
+        args=[cells],
+        plugin_path=LIB,
+        function_name="cells_outer_ring",
+        is_elementwise=True,
+    )
+
+
+def cells_perimeter_edges(cells: IntoExprColumn) -> pl.Expr:
+    """
+    Count the exposed edges of a set of cells.
+
+    Returns the number of directed edges leading from each row's cell set to a cell
+    outside it, i.e. the length of the set's outline in cell edges.
+
+    Null or invalid cells are ignored, and all cells in a set should share a
+    resolution.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        `List` column of H3 cells (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+
+    #### Returns
+    Expr
+        Expression returning the edge count as `pl.UInt32` per row, or `None` for null
+        lists.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"cell": ["8928308280fffff"]})
+    >>> df = df.select(cells=polars_h3.grid_disk("cell", 1))
+    >>> df.select(polars_h3.cells_perimeter_edges("cells"))
+    shape: (1, 1)
+    ┌───────┐
+    │ cells │
+    │ ---   │
+    │ u32   │
+    ╞═══════╡
+    │ 18    │
+    └───────┘
+    ```
+    """
+    return register_plugin_function(
+        args=[cells],
+        plugin_path=LIB,
+        function_name="cells_perimeter_edges",
+        is_elementwise=True,
     )
@@ -1,12 +1,20 @@
 from __future__ import annotations
 
 from pathlib import Path
-from typing import TYPE_CHECKING, Union
+from typing import TYPE_CHECKING, Literal, Union
 
 import polars as pl
 from polars.plugins import register_plugin_function
 
-from .utils import HexResolution, assert_valid_resolution
+from ._types import AngleUnit, AntimeridianMode, CoordinateOutput, PointOutput
+from .utils import (
+    POINT_OUTPUTS,
+    HexResolution,
+    assert_valid_resolution,
+    coordinate_function,
+    float_expr,
+    resolution_expr,
+)
 
 if TYPE_CHECKING:
     from polars_h3.typing import IntoExprColumn
@@ -14,12 +22,16 @@ if TYPE_CHECKING:
 
 LIB = Path(__file__).parent.parent
 
+ContainmentMode = Literal["centroid", "contains", "intersects", "covers"]
+CircleMode = Literal["centroid", "any"]
+
 
 def latlng_to_cell(
     lat: IntoExprColumn,
     lng: IntoExprColumn,
-    resolution: HexResolution,
+    resolution: Union[HexResolution, IntoExprColumn],
     return_dtype: Union[type[pl.Utf8], type[pl.UInt64], type[pl.Int64]] = pl.UInt64,
+    unit: AngleUnit = "deg",
 ) -> pl.Expr:
     """
     Convert latitude/longitude coordinates to H3 cell indices.
@@ -29,10 +41,13 @@ def latlng_to_cell(
         - Name of the column containing latitude values (as `pl.Float64`)
     - `lng_col`: str
         - Name of the column containing longitude values (as `pl.Float64`)
-    - `resolution`: int (0-15)
-        - H3 resolution level
+    - `resolution`: int (0-15) or IntoExprColumn
+        - H3 resolution level, either for all rows or as a per-row integer column.
+          Rows with a null or invalid per-row resolution return `None`.
     - `return_dtype`: polars.DataType
         - Return type for the H3 indices. `pl.UInt64`, `pl.Int64`, or `pl.Utf8`
+    - `unit`: str
+        - Unit of `lat` and `lng`, `"deg"` (default) or `"rad"`.
 
     #### Returns
     Expr
@@ -76,29 +91,47 @@ def latlng_to_cell(
     ╞═════════╪══════════╪═════════════════════╡
     │ 0.0     │ 0.0      │ 583031433791012863  │
     └─────────┴──────────┴─────────────────────┘
+    >>> # Using a per-row resolution
+    >>> df = pl.DataFrame({
+    ...     "lat": [37.7752702151959, 37.7752702151959],
+    ...     "lng": [-122.418307270836, -122.418307270836],
+    ...     "res": [9, 5],
+    ... })
+    >>> df.with_columns(
+    ...     h3_cell=polars_h3.latlng_to_cell("lat", "lng", "res", return_dtype=pl.Utf8)
+    ... )
+    shape: (2, 4)
+    ┌──────────────────┬───────────────────┬─────┬─────────────────┐
+    │ lat              │ lng               │ res │ h3_cell         │
+    │ ---              │ ---               │ --- │ ---             │
+    │ f64              │ f64               │ i64 │ str             │
+    ╞══════════════════╪═══════════════════╪═════╪═════════════════╡
+    │ 37.7752702151959 │ -122.418307270836 │ 9   │ 8928308280fffff │
+    │ 37.7752702151959 │ -122.418307270836 │ 5   │ 85283083fffffff │
+    └──────────────────┴───────────────────┴─────┴─────────────────┘
     ```
 
     #### Errors
     - `ValueError`: If resolution is invalid (must be between 0 and 15)
     - `ComputeError`: If input coordinates contain null values
     """
-    assert_valid_resolution(resolution)
+    resolution = resolution_expr(resolution)
 
     if return_dtype == pl.Utf8:
         expr = register_plugin_function(
-            args=[lat, lng],
+            args=[lat, lng, resolution],
             plugin_path=LIB,
             function_name="latlng_to_cell_string",
             is_elementwise=True,
-            kwargs={"resolution": resolution},
+            kwargs={"unit": unit},
         )
     else:
         expr = register_plugin_function(
-            args=[lat, lng],
+            args=[lat, lng, resolution],
             plugin_path=LIB,
             function_name="latlng_to_cell",
             is_elementwise=True,
-            kwargs={"resolution": resolution},
+            kwargs={"unit": unit},
         )
         if return_dtype != pl.UInt64:
             expr = expr.cast(return_dtype)
@@ -106,13 +139,85 @@ def latlng_to_cell(
     return expr
 
 
-def cell_to_lat(cell: IntoExprColumn) -> pl.Expr:
+def point_to_cell(
+    point: IntoExprColumn,
+    resolution: Union[HexResolution, IntoExprColumn],
+    return_dtype: Union[type[pl.Utf8], type[pl.UInt64], type[pl.Int64]] = pl.UInt64,
+    unit: AngleUnit = "deg",
+) -> pl.Expr:
+    """
+    Convert GeoArrow points into H3 cells.
+
+    This is `latlng_to_cell` for GeoArrow point columns (`Struct{x, y}` with
+    `x = lng` and `y = lat`), such as those produced by
+    `cell_to_latlng(..., output="geoarrow")`. Null or non-finite points return
+    `None`.
+
+    #### Parameters
+    - `point`: IntoExprColumn
+        Column or expression containing GeoArrow points.
+    - `resolution`: int (0-15) or IntoExprColumn
+        H3 resolution level, either for all rows or as a per-row integer column.
+    - `return_dtype`: polars.DataType
+        Return type for the H3 indices. `pl.UInt64`, `pl.Int64`, or `pl.Utf8`
+    - `unit`: str
+        Unit of the point coordinates, `"deg"` (default) or `"rad"`.
+
+    #### Returns
+    Expr
+        Expression containing H3 cell indices.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"point": [{"x": -122.418307270836, "y": 37.7752702151959}]})
+    >>> df.with_columns(h3_cell=polars_h3.point_to_cell("point", 9, return_dtype=pl.Utf8))
+    shape: (1, 2)
+    ┌─────────────────────────────────┬─────────────────┐
+    │ point                           │ h3_cell         │
+    │ ---                             │ ---             │
+    │ struct[2]                       │ str             │
+    ╞═════════════════════════════════╪═════════════════╡
+    │ {-122.418307,37.77527}          │ 8928308280fffff │
+    └─────────────────────────────────┴─────────────────┘
+    ```
+
+    #### Errors
+    - `ValueError`: If resolution is invalid (must be between 0 and 15)
+    - `ComputeError`: If `point` is not a `Struct{x, y}` column
+    """
+    resolution = resolution_expr(resolution)
+
+    if return_dtype == pl.Utf8:
+        expr = register_plugin_function(
+            args=[point, resolution],
+            plugin_path=LIB,
+            function_name="point_to_cell_string",
+            is_elementwise=True,
+            kwargs={"unit": unit},
+        )
+    else:
+        expr = register_plugin_function(
+            args=[point, resolution],
+            plugin_path=LIB,
+            function_name="point_to_cell",
+            is_elementwise=True,
+            kwargs={"unit": unit},
+        )
+        if return_dtype != pl.UInt64:
+            expr = expr.cast(return_dtype)
+
+    return expr
+
+
+def cell_to_lat(cell: IntoExprColumn, unit: AngleUnit = "deg") -> pl.Expr:
     """
     Extract the latitude coordinate from H3 cell indices.
 
     #### Parameters
     - `cell`: IntoExprColumn
         Column or expression containing H3 cell indices (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `unit`: str
+        Unit of the returned latitude, `"deg"` (default) or `"rad"`.
 
     #### Returns
     Expr
@@ -158,16 +263,19 @@ def cell_to_lat(cell: IntoExprColumn) -> pl.Expr:
         plugin_path=LIB,
         function_name="cell_to_lat",
         is_elementwise=True,
+        kwargs={"unit": unit},
     )
 
 
-def cell_to_lng(cell: IntoExprColumn) -> pl.Expr:
+def cell_to_lng(cell: IntoExprColumn, unit: AngleUnit = "deg") -> pl.Expr:
     """
     Extract the longitude coordinate from H3 cell indices.
 
     #### Parameters
     - `cell`: IntoExprColumn
         Column or expression containing H3 cell indices (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `unit`: str
+        Unit of the returned longitude, `"deg"` (default) or `"rad"`.
 
     #### Returns
     Expr
@@ -213,26 +321,40 @@ def cell_to_lng(cell: IntoExprColumn) -> pl.Expr:
         plugin_path=LIB,
         function_name="cell_to_lng",
         is_elementwise=True,
+        kwargs={"unit": unit},
     )
 
 
-def cell_to_latlng(cell: IntoExprColumn) -> pl.Expr:
+def cell_to_latlng(
+    cell: IntoExprColumn, output: PointOutput = "list", unit: AngleUnit = "deg"
+) -> pl.Expr:
     """
     Convert H3 cells into a list of [latitude, longitude].
 
     #### Parameters
     - `cell`: IntoExprColumn
         Column or expression containing H3 cell indices (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `output`: str
+        Coordinate layout of the result:
+        - `"list"`: a `[lat, lng]` list (default)
+        - `"struct"`: `Struct{lat, lng}`, so fields are read by name with
+          `.struct.field("lat")`
+        - `"geoarrow"`: a GeoArrow point, `Struct{x: lng, y: lat}`
+    - `unit`: str
+        Unit of the returned coordinates, `"deg"` (default) or `"rad"`.
 
     #### Returns
     -------
     Expr
-        Expression returning a list of floats: [lat, lng] for each H3 cell.
+        Expression returning a list of floats: [lat, lng] for each H3 cell, a
+        `Struct{lat, lng}`, or a GeoArrow point.
 
     Raises
     ------
     ComputeError
         If null or invalid H3 cell indices are encountered.
+    ValueError
+        If `output` is not a recognized layout.
 
     Examples
     --------
@@ -252,10 +374,7 @@ def cell_to_latlng(cell: IntoExprColumn) -> pl.Expr:
     └─────────────────────────┘
 
     From there, you could easily extract latitude/longitude as separate columns:
-    >>> df.select([
-    ...     polars_h3.cell_to_latlng("cell").arr.get(0).alias("lat"),
-    ...     polars_h3.cell_to_latlng("cell").arr.get(1).alias("lng"),
-    ... ])
+    >>> df.select(polars_h3.cell_to_latlng("cell", output="struct").struct.unnest())
     shape: (1, 2)
     ┌───────────┬───────────┐
     │ lat       │ lng       │
@@ -268,8 +387,9 @@ def cell_to_latlng(cell: IntoExprColumn) -> pl.Expr:
     return register_plugin_function(
         args=[cell],
         plugin_path=LIB,
-        function_name="cell_to_latlng",
+        function_name=coordinate_function("cell_to_latlng", output, POINT_OUTPUTS),
         is_elementwise=True,
+        kwargs={"unit": unit},
     )
 
 
@@ -374,7 +494,12 @@ def local_ij_to_cell(
     )
 
 
-def cell_to_boundary(cell: IntoExprColumn) -> pl.Expr:
+def cell_to_boundary(
+    cell: IntoExprColumn,
+    output: CoordinateOutput = "list",
+    antimeridian: AntimeridianMode = "ignore",
+    unit: AngleUnit = "deg",
+) -> pl.Expr:
     """
     Retrieve the polygon boundary coordinates of the given H3 cell.
 
@@ -383,11 +508,25 @@ def cell_to_boundary(cell: IntoExprColumn) -> pl.Expr:
     #### Parameters
     - `cell`: IntoExprColumn
         Column or expression containing H3 cell indices (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `output`: str
+        Coordinate layout of the result:
+        - `"list"`: a list of `[lat, lng]` pairs (default)
+        - `"geoarrow"`: a GeoArrow polygon, i.e. a list of closed rings of
+          `Struct{x: lng, y: lat}` points
+    - `antimeridian`: str
+        How cells crossing ±180° longitude are returned:
+        - `"ignore"`: longitudes stay in `[-180, 180]` (default)
+        - `"split"`: cells are split at the antimeridian into a multipolygon
+        - `"unwrap"`: longitudes continue past ±180° so the ring stays continuous
+    - `unit`: str
+        Unit of the returned coordinates, `"deg"` (default) or `"rad"`.
 
     #### Returns
     Expr
         A `pl.Expr` returning a list of `Float64` values representing the boundary vertices
-        of the cell in latitude-longitude pairs.
+        of the cell in latitude-longitude pairs, or a GeoArrow polygon. With
+        `antimeridian="split"`, each row is instead a list of such rings (or a GeoArrow
+        multipolygon), one per part.
 
     #### Examples
     ```python
@@ -406,10 +545,578 @@ def cell_to_boundary(cell: IntoExprColumn) -> pl.Expr:
 
     #### Errors
     - `ComputeError`: If null or invalid H3 cell indices are encountered.
+    - `ValueError`: If `output` is not a recognized layout.
+    - `ComputeError`: If `antimeridian` is not a recognized mode.
+    """
+    return register_plugin_function(
+        args=[cell],
+        plugin_path=LIB,
+        function_name=coordinate_function("cell_to_boundary", output),
+        is_elementwise=True,
+        kwargs={"antimeridian": antimeridian, "unit": unit},
+    )
+
+
+def cell_to_boundary_wkt(
+    cell: IntoExprColumn, antimeridian: AntimeridianMode = "ignore"
+) -> pl.Expr:
+    """
+    Retrieve the boundary of the given H3 cell as a WKT `POLYGON`.
+
+    Coordinates are in lng/lat order and the ring is closed (the first vertex is
+    repeated at the end), as expected by GIS tools.
+
+    #### Parameters
+    - `cell`: IntoExprColumn
+        Column or expression containing H3 cell indices (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `antimeridian`: str
+        How cells crossing ±180° longitude are returned:
+        - `"ignore"`: longitudes stay in `[-180, 180]` (default)
+        - `"split"`: cells are split at the antimeridian into a `MULTIPOLYGON`
+        - `"unwrap"`: longitudes continue past ±180° so the ring stays continuous
+
+    #### Returns
+    Expr
+        Expression returning the cell boundary as a WKT string.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "cell": ["8a1fb46622dffff"]
+    ... })
+    >>> df.select(polars_h3.cell_to_boundary_wkt("cell"))
+    shape: (1, 1)
+    ┌───────────────────────────────────┐
+    │ cell                              │
+    │ ---                               │
+    │ str                               │
+    ╞═══════════════════════════════════╡
+    │ POLYGON((2.373644355571729 48.85… │
+    └───────────────────────────────────┘
+    ```
+    """
+    return register_plugin_function(
+        args=[cell],
+        plugin_path=LIB,
+        function_name="cell_to_boundary_wkt",
+        is_elementwise=True,
+        kwargs={"antimeridian": antimeridian},
+    )
+
+
+def cell_to_boundary_wkb(
+    cell: IntoExprColumn, antimeridian: AntimeridianMode = "ignore"
+) -> pl.Expr:
+    """
+    Retrieve the boundary of the given H3 cell as a WKB `Polygon`.
+
+    The geometry is encoded as little-endian ISO WKB with lng/lat coordinates and a
+    closed ring, so it can be written straight to GeoParquet or a warehouse geometry
+    column.
+
+    #### Parameters
+    - `cell`: IntoExprColumn
+        Column or expression containing H3 cell indices (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `antimeridian`: str
+        How cells crossing ±180° longitude are returned:
+        - `"ignore"`: longitudes stay in `[-180, 180]` (default)
+        - `"split"`: cells are split at the antimeridian into a `MultiPolygon`
+        - `"unwrap"`: longitudes continue past ±180° so the ring stays continuous
+
+    #### Returns
+    Expr
+        Expression returning the cell boundary as `pl.Binary`.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "cell": ["8a1fb46622dffff"]
+    ... })
+    >>> df.select(polars_h3.cell_to_boundary_wkb("cell"))
+    shape: (1, 1)
+    ┌───────────────────────────────────┐
+    │ cell                              │
+    │ ---                               │
+    │ binary                            │
+    ╞═══════════════════════════════════╡
+    │ b"\x01\x03\x00\x00\x00\x01\x00… │
+    └───────────────────────────────────┘
+    ```
     """
     return register_plugin_function(
         args=[cell],
         plugin_path=LIB,
-        function_name="cell_to_boundary",
+        function_name="cell_to_boundary_wkb",
+        is_elementwise=True,
+        kwargs={"antimeridian": antimeridian},
+    )
+
+
+def polygon_to_cells(
+    polygon: IntoExprColumn,
+    resolution: HexResolution,
+    containment: ContainmentMode = "centroid",
+    return_dtype: Union[type[pl.Utf8], type[pl.UInt64], type[pl.Int64]] = pl.UInt64,
+    unit: AngleUnit = "deg",
+) -> pl.Expr:
+    """
+    Fill polygons with the H3 cells that cover them.
+
+    Accepts WKT `POLYGON` and `MULTIPOLYGON` geometries (holes included) in lng/lat
+    order, or polygons as `[lat, lng]` coordinate rings, the layout produced by
+    `cell_to_boundary`. Rows that are null, malformed, or not a polygon return `None`.
+
+    #### Parameters
+    - `polygon`: IntoExprColumn
+        Column or expression containing polygons as one of:
+        - WKT strings (`pl.Utf8`)
+        - a single exterior ring of `[lat, lng]` pairs (`List(List(Float64))`)
+        - an exterior ring followed by hole rings (`List(List(List(Float64)))`)
+    - `resolution`: int (0-15)
+        H3 resolution level of the returned cells.
+    - `containment`: str
+        How to decide whether a cell belongs to the polygon:
+        - `"centroid"`: the cell's centroid is inside the polygon (default)
+        - `"contains"`: the cell's boundary is fully inside the polygon
+        - `"intersects"`: the cell's boundary intersects the polygon
+        - `"covers"`: like `"intersects"`, but also returns the enclosing cell
+          when the polygon is smaller than a cell
+    - `return_dtype`: polars.DataType
+        Return type for the H3 indices. `pl.UInt64`, `pl.Int64`, or `pl.Utf8`
+    - `unit`: str
+        Unit of `[lat, lng]` rings, `"deg"` (default) or `"rad"`. WKT is always
+        read in degrees.
+
+    #### Returns
+    Expr
+        Expression returning a list of H3 cells for each polygon.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "polygon": ["POLYGON((-122.4089 37.813318, -122.3544 37.7198, -122.5149 37.7081, -122.5283 37.7947, -122.4089 37.813318))"]
+    ... })
+    >>> df.select(polars_h3.polygon_to_cells("polygon", 7, return_dtype=pl.Utf8))
+    shape: (1, 1)
+    ┌───────────────────────────────────┐
+    │ polygon                           │
+    │ ---                               │
+    │ list[str]                         │
+    ╞═══════════════════════════════════╡
+    │ ["872830820ffffff", "872830821ff… │
+    └───────────────────────────────────┘
+    ```
+
+    #### Errors
+    - `ValueError`: If resolution is invalid (must be between 0 and 15)
+    - `ComputeError`: If the containment mode or `unit` is not recognized, or
+      `polygon` is neither a string nor a list column
+    """
+    assert_valid_resolution(resolution)
+
+    if return_dtype == pl.Utf8:
+        expr = register_plugin_function(
+            args=[polygon],
+            plugin_path=LIB,
+            function_name="polygon_to_cells_string",
+            is_elementwise=True,
+            kwargs={
+                "resolution": resolution,
+                "containment": containment,
+                "unit": unit,
+            },
+        )
+    else:
+        expr = register_plugin_function(
+            args=[polygon],
+            plugin_path=LIB,
+            function_name="polygon_to_cells",
+            is_elementwise=True,
+            kwargs={
+                "resolution": resolution,
+                "containment": containment,
+                "unit": unit,
+            },
+        )
+        if return_dtype != pl.UInt64:
+            expr = expr.cast(pl.List(return_dtype))
+
+    return expr
+
+
+def bbox_to_cells(
+    min_lat: Union[float, IntoExprColumn],
+    min_lng: Union[float, IntoExprColumn],
+    max_lat: Union[float, IntoExprColumn],
+    max_lng: Union[float, IntoExprColumn],
+    resolution: Union[HexResolution, IntoExprColumn],
+    return_dtype: Union[type[pl.Utf8], type[pl.UInt64], type[pl.Int64]] = pl.UInt64,
+    unit: AngleUnit = "deg",
+) -> pl.Expr:
+    """
+    Fill lat/lng bounding boxes with the H3 cells that cover them.
+
+    Every cell that overlaps the box is returned, so a box smaller than a cell still
+    yields the cell it falls in. A box whose `min_lng` is greater than its `max_lng`
+    crosses the antimeridian, e.g. `min_lng=170, max_lng=-170` spans 20 degrees of
+    longitude around 180. The ranges below are in degrees; radian bounds are converted
+    before they are checked. Rows with a null coordinate, a latitude outside -90..90, a
+    longitude outside -180..180, or `min_lat > max_lat` return `None`.
+
+    #### Parameters
+    - `min_lat`, `min_lng`, `max_lat`, `max_lng`: float or IntoExprColumn
+        Bounds of the box in `unit`, either for all rows or as per-row columns.
+    - `resolution`: int (0-15) or IntoExprColumn
+        H3 resolution level, either for all rows or as a per-row integer column.
+        Rows with a null or invalid per-row resolution return `None`.
+    - `return_dtype`: polars.DataType
+        Return type for the H3 indices. `pl.UInt64`, `pl.Int64`, or `pl.Utf8`
+    - `unit`: str
+        Unit of the bounds, `"deg"` (default) or `"rad"`.
+
+    #### Returns
+    Expr
+        Expression returning a sorted list of H3 cells for each box.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "min_lat": [37.7], "min_lng": [-122.5], "max_lat": [37.8], "max_lng": [-122.4]
+    ... })
+    >>> df.select(
+    ...     cells=polars_h3.bbox_to_cells(
+    ...         "min_lat", "min_lng", "max_lat", "max_lng", 4, return_dtype=pl.Utf8
+    ...     )
+    ... )
+    shape: (1, 1)
+    ┌─────────────────────┐
+    │ cells               │
+    │ ---                 │
+    │ list[str]           │
+    ╞═════════════════════╡
+    │ ["8428309ffffffff"] │
+    └─────────────────────┘
+    ```
+
+    #### Errors
+    - `ValueError`: If a scalar resolution is invalid (must be between 0 and 15)
+    - `ComputeError`: If a bound is not numeric, column lengths do not match, or
+      `unit` is not `"deg"` or `"rad"`
+    """
+    args = [float_expr(bound) for bound in (min_lat, min_lng, max_lat, max_lng)]
+    args.append(resolution_expr(resolution))
+
+    if return_dtype == pl.Utf8:
+        return register_plugin_function(
+            args=args,
+            plugin_path=LIB,
+            function_name="bbox_to_cells_string",
+            is_elementwise=True,
+            kwargs={"unit": unit},
+        )
+
+    expr = register_plugin_function(
+        args=args,
+        plugin_path=LIB,
+        function_name="bbox_to_cells",
+        is_elementwise=True,
+        kwargs={"unit": unit},
+    )
+    if return_dtype != pl.UInt64:
+        expr = expr.cast(pl.List(return_dtype))
+    return expr
+
+
+def circle_to_cells(
+    lat: Union[float, IntoExprColumn],
+    lng: Union[float, IntoExprColumn],
+    radius_m: Union[float, IntoExprColumn],
+    resolution: Union[HexResolution, IntoExprColumn],
+    mode: CircleMode = "centroid",
+    return_dtype: Union[type[pl.Utf8], type[pl.UInt64], type[pl.Int64]] = pl.UInt64,
+    max_cells: int = 1_000_000,
+    unit: AngleUnit = "deg",
+) -> pl.Expr:
+    """
+    Find the H3 cells within a great-circle radius of a point.
+
+    Unlike approximating a `grid_disk` k from the average edge length, the result
+    holds exactly the cells matching `mode`. Rows with a null or invalid coordinate,
+    a null, negative or non-finite radius, or a circle covering more than `max_cells`
+    cells return `None`.
+
+    #### Parameters
+    - `lat`, `lng`: float or IntoExprColumn
+        Center of the circle in `unit`, either for all rows or as per-row columns.
+    - `radius_m`: float or IntoExprColumn
+        Radius in meters, either for all rows or as a per-row column.
+    - `resolution`: int (0-15) or IntoExprColumn
+        H3 resolution level, either for all rows or as a per-row integer column.
+        Rows with a null or invalid per-row resolution return `None`.
+    - `mode`: str
+        Which cells belong to the circle:
+        - `"centroid"`: the cell's centroid lies within the radius (default)
+        - `"any"`: any part of the cell lies within the radius
+    - `return_dtype`: polars.DataType
+        Return type for the H3 indices. `pl.UInt64`, `pl.Int64`, or `pl.Utf8`
+    - `max_cells`: int
+        Maximum number of cells a circle may cover, estimated from its area and the
+        resolution's average cell area. Rows over the limit return `None`, guarding
+        against a large radius at a fine resolution enumerating billions of cells.
+    - `unit`: str
+        Unit of `lat` and `lng`, `"deg"` (default) or `"rad"`. `radius_m` is always
+        in meters.
+
+    #### Returns
+    Expr
+        Expression returning a sorted list of H3 cells for each circle.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "lat": [37.7752702151959, 37.7752702151959],
+    ...     "lng": [-122.418307270836, -122.418307270836],
+    ...     "radius_m": [500.0, 100.0],
+    ... })
+    >>> df.select(
+    ...     centroid=polars_h3.circle_to_cells("lat", "lng", "radius_m", 9).list.len(),
+    ...     any=polars_h3.circle_to_cells("lat", "lng", "radius_m", 9, mode="any").list.len(),
+    ... )
+    shape: (2, 2)
+    ┌──────────┬─────┐
+    │ centroid │ any │
+    │ ---      │ --- │
+    │ u32      │ u32 │
+    ╞══════════╪═════╡
+    │ 8        │ 14  │
+    │ 0        │ 3   │
+    └──────────┴─────┘
+    ```
+
+    #### Errors
+    - `ValueError`: If a scalar resolution is invalid (must be between 0 and 15)
+    - `ValueError`: If `max_cells < 1`
+    - `ComputeError`: If `mode` or `unit` is not recognized, an input is not numeric,
+      or column lengths do not match
+    """
+    if max_cells < 1:
+        raise ValueError("max_cells must be at least 1")
+    args = [float_expr(lat), float_expr(lng), float_expr(radius_m)]
+    args.append(resolution_expr(resolution))
+
+    if return_dtype == pl.Utf8:
+        return register_plugin_function(
+            args=args,
+            plugin_path=LIB,
+            function_name="circle_to_cells_string",
+            is_elementwise=True,
+            kwargs={"mode": mode, "max_cells": max_cells, "unit": unit},
+        )
+
+    expr = register_plugin_function(
+        args=args,
+        plugin_path=LIB,
+        function_name="circle_to_cells",
+        is_elementwise=True,
+        kwargs={"mode": mode, "max_cells": max_cells, "unit": unit},
+    )
+    if return_dtype != pl.UInt64:
+        expr = expr.cast(pl.List(return_dtype))
+    return expr
+
+
+def cell_contains_latlng(
+    cell: IntoExprColumn,
+    lat: Union[float, IntoExprColumn],
+    lng: Union[float, IntoExprColumn],
+    unit: AngleUnit = "deg",
+) -> pl.Expr:
+    """
+    Check whether a point lies inside an H3 cell of any resolution.
+
+    The point is indexed at each row's cell resolution, so the same column can mix
+    resolutions, and testing against a coarser parent finds points within it.
+    Rows with a null or invalid cell or coordinate return `None`.
+
+    #### Parameters
+    - `cell`: IntoExprColumn
+        Column or expression containing H3 cell indices (`pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `lat`, `lng`: float or IntoExprColumn
+        Point coordinates in `unit`, either for all rows or as per-row columns.
+    - `unit`: str
+        Unit of `lat` and `lng`, `"deg"` (default) or `"rad"`.
+
+    #### Returns
+    Expr
+        Boolean expression, `True` where the point lies inside the cell.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "cell": ["8928308280fffff", "85283083fffffff", "8928308280fffff"],
+    ...     "lat": [37.7752702151959, 37.7752702151959, 0.0],
+    ...     "lng": [-122.418307270836, -122.418307270836, 0.0],
+    ... })
+    >>> df.with_columns(inside=polars_h3.cell_contains_latlng("cell", "lat", "lng"))
+    shape: (3, 4)
+    ┌─────────────────┬──────────────────┬───────────────────┬────────┐
+    │ cell            │ lat              │ lng               │ inside │
+    │ ---             │ ---              │ ---               │ ---    │
+    │ str             │ f64              │ f64               │ bool   │
+    ╞═════════════════╪══════════════════╪═══════════════════╪════════╡
+    │ 8928308280fffff │ 37.7752702151959 │ -122.418307270836 │ true   │
+    │ 85283083fffffff │ 37.7752702151959 │ -122.418307270836 │ true   │
+    │ 8928308280fffff │ 0.0              │ 0.0               │ false  │
+    └─────────────────┴──────────────────┴───────────────────┴────────┘
+    ```
+
+    #### Errors
+    - `ComputeError`: If `lat` or `lng` is not numeric, column lengths do not match, or
+      `unit` is not `"deg"` or `"rad"`
+    """
+    return register_plugin_function(
+        args=[cell, float_expr(lat), float_expr(lng)],
+        plugin_path=LIB,
+        function_name="cell_contains_latlng",
         is_elementwise=True,
+        kwargs={"unit": unit},
+    )
+
+
+def cells_to_multi_polygon_wkt(cells: IntoExprColumn) -> pl.Expr:
+    """
+    Dissolve each row's set of H3 cells into a WKT `MULTIPOLYGON` outline.
+
+    Shared edges between adjacent cells are removed, so contiguous cells become one
+    polygon and enclosed gaps become holes. Duplicate cells are ignored, and a set
+    that mixes resolutions returns `None`. To dissolve a whole cell column, or each
+    group in a `group_by`, use `cells_to_multi_polygon_wkt_agg`.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        `List` column of H3 cells as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`.
+
+    #### Returns
+    Expr
+        Expression returning each row's outline as a WKT string in lng/lat order,
+        or `None` for a null row or a set that mixes resolutions.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "cells": [["8928308280fffff", "8928308280bffff"], ["8a1fb46622dffff"]],
+    ... })
+    >>> df.with_columns(wkt=polars_h3.cells_to_multi_polygon_wkt("cells"))
+    shape: (2, 2)
+    ┌───────────────────────────────────┬───────────────────────────────────┐
+    │ cells                             │ wkt                               │
+    │ ---                               │ ---                               │
+    │ list[str]                         │ str                               │
+    ╞═══════════════════════════════════╪═══════════════════════════════════╡
+    │ ["8928308280fffff", "8928308280b… │ MULTIPOLYGON(((-122.4159401398... │
+    │ ["8a1fb46622dffff"]               │ MULTIPOLYGON(((2.372742411267... │
+    └───────────────────────────────────┴───────────────────────────────────┘
+    ```
+
+    #### Errors
+    - `ComputeError`: If `cells` is not a `List` column.
+    """
+    return register_plugin_function(
+        args=[cells],
+        plugin_path=LIB,
+        function_name="cells_to_multi_polygon_wkt",
+        is_elementwise=True,
+    )
+
+
+def cells_to_multi_polygon_wkt_agg(cells: IntoExprColumn) -> pl.Expr:
+    """
+    Dissolve a whole column of H3 cells into a single WKT `MULTIPOLYGON` outline.
+
+    The aggregating form of `cells_to_multi_polygon_wkt`: the column is collapsed
+    into one value, so in a `group_by` it returns one string per group. Duplicate
+    cells are ignored, and a set that mixes resolutions returns `None`.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        H3 cells as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`.
+
+    #### Returns
+    Expr
+        Expression returning the dissolved outline as a WKT string in lng/lat order,
+        or `None` for a set that mixes resolutions.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "region": ["a", "a", "b"],
+    ...     "cell": ["8928308280fffff", "8928308280bffff", "8a1fb46622dffff"],
+    ... })
+    >>> df.group_by("region").agg(wkt=polars_h3.cells_to_multi_polygon_wkt_agg("cell"))
+    shape: (2, 2)
+    ┌────────┬───────────────────────────────────┐
+    │ region │ wkt                               │
+    │ ---    │ ---                               │
+    │ str    │ str                               │
+    ╞════════╪═══════════════════════════════════╡
+    │ a      │ MULTIPOLYGON(((-122.4159401398... │
+    │ b      │ MULTIPOLYGON(((2.372742411267... │
+    └────────┴───────────────────────────────────┘
+    ```
+
+    #### Errors
+    - `ComputeError`: If `cells` is a `List` column.
+    """
+    return register_plugin_function(
+        args=[cells],
+        plugin_path=LIB,
+        function_name="cells_to_multi_polygon_wkt_agg",
+        returns_scalar=True,
+    )
+
+
+def cells_to_geojson(cells: IntoExprColumn, *properties: IntoExprColumn) -> pl.Expr:
+    """
+    Export H3 cells as a GeoJSON `FeatureCollection` string.
+
+    Each valid cell becomes a `Feature` whose `id` is the cell's hex string and whose
+    geometry is the cell boundary in lng/lat order. Cells crossing the antimeridian are
+    split into a `MultiPolygon` so they render correctly in kepler.gl, Mapbox or QGIS.
+    Null or invalid cells are skipped.
+
+    Any additional columns are written to each feature's `properties`, keyed by column
+    name. Numbers, booleans and strings are kept as JSON values; other types are
+    written as strings. `NaN` and infinite floats become `null`.
+
+    The whole column is collapsed into a single value, which also makes it usable as a
+    `group_by` aggregation returning one string per group.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        H3 cells as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`.
+    - `*properties`: IntoExprColumn
+        Columns to include as feature properties.
+
+    #### Returns
+    Expr
+        Expression returning the `FeatureCollection` as a JSON string.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "cell": ["892830828cfffff", "825ba7fffffffff"],
+    ...     "count": [10, 20],
+    ... })
+    >>> df.select(polars_h3.cells_to_geojson("cell", "count")).item()
+    '{"type":"FeatureCollection","features":[{"type":"Feature","id":"892830828cfffff",...'
+    ```
+
+    #### Errors
+    - `ComputeError`: If a property column's length doesn't match `cells`.
+    """
+    return register_plugin_function(
+        args=[cells, *properties],
+        plugin_path=LIB,
+        function_name="cells_to_geojson",
+        returns_scalar=True,
     )
@@ -1,12 +1,12 @@
 from __future__ import annotations
 
 from pathlib import Path
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Union
 
 import polars as pl
 from polars.plugins import register_plugin_function
 
-from .utils import HexResolution, assert_valid_resolution
+from .utils import HexResolution, assert_valid_resolution, resolution_expr
 
 if TYPE_CHECKING:
     from polars_h3.typing import IntoExprColumn
@@ -288,7 +288,7 @@ def get_icosahedron_faces(expr: IntoExprColumn) -> pl.Expr:
 
 def cell_to_parent(
     cell: IntoExprColumn,
-    resolution: HexResolution,
+    resolution: Union[HexResolution, IntoExprColumn],
 ) -> pl.Expr:
     """
     Retrieve the parent cell of a given H3 cell at a specified resolution.
@@ -296,8 +296,9 @@ def cell_to_parent(
     #### Parameters
     - `cell`: IntoExprColumn
         H3 cells as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`.
-    - `resolution`: int (0-15)
-        Target parent resolution.
+    - `resolution`: int (0-15) | IntoExprColumn
+        Target parent resolution, either one value for all rows or a per-row column.
+        Rows whose resolution is null, invalid, or finer than the cell return `None`.
 
     #### Returns
     Expr
@@ -311,25 +312,27 @@ def cell_to_parent(
     ... )
     ```
     """
-    assert_valid_resolution(resolution)
     return register_plugin_function(
-        args=[cell],
+        args=[cell, resolution_expr(resolution)],
         plugin_path=LIB,
         function_name="cell_to_parent",
         is_elementwise=True,
-        kwargs={"resolution": resolution},
     )
 
 
-def cell_to_center_child(cell: IntoExprColumn, resolution: HexResolution) -> pl.Expr:
+def cell_to_center_child(
+    cell: IntoExprColumn,
+    resolution: Union[HexResolution, IntoExprColumn],
+) -> pl.Expr:
     """
     Retrieve the center child cell of an H3 cell at a specified resolution.
 
     #### Parameters
     - `cell`: IntoExprColumn
         H3 cells as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`.
-    - `resolution`: int (0-15)
-        Target resolution for the center child.
+    - `resolution`: int (0-15) | IntoExprColumn
+        Target resolution for the center child, either one value or a per-row column.
+        Rows whose resolution is null or invalid return `None`.
 
     #### Returns
     Expr
@@ -343,24 +346,27 @@ def cell_to_center_child(cell: IntoExprColumn, resolution: HexResolution) -> pl.
     ... )
     ```
     """
-    assert_valid_resolution(resolution)
     return register_plugin_function(
-        args=[cell],
+        args=[cell, resolution_expr(resolution)],
         plugin_path=LIB,
         function_name="cell_to_center_child",
         is_elementwise=True,
-        kwargs={"resolution": resolution},
     )
 
 
-def cell_to_children_size(cell: IntoExprColumn, resolution: HexResolution) -> pl.Expr:
+def cell_to_children_size(
+    cell: IntoExprColumn,
+    resolution: Union[HexResolution, IntoExprColumn],
+) -> pl.Expr:
     """
     Get the number of children cells at a specified resolution.
 
     #### Parameters
     - `cell`: IntoExprColumn
         H3 cells as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`.
-    - `resolution`: int (0-15)
+    - `resolution`: int (0-15) | IntoExprColumn
+        Target child resolution, either one value or a per-row column.
+        Rows whose resolution is null or invalid return `None`.
 
     #### Returns
     Expr
@@ -374,24 +380,27 @@ def cell_to_children_size(cell: IntoExprColumn, resolution: HexResolution) -> pl
     ... )
     ```
     """
-    assert_valid_resolution(resolution)
     return register_plugin_function(
-        args=[cell],
+        args=[cell, resolution_expr(resolution)],
         plugin_path=LIB,
         function_name="cell_to_children_size",
         is_elementwise=True,
-        kwargs={"resolution": resolution},
     )
 
 
-def cell_to_children(cell: IntoExprColumn, resolution: HexResolution) -> pl.Expr:
+def cell_to_children(
+    cell: IntoExprColumn,
+    resolution: Union[HexResolution, IntoExprColumn],
+) -> pl.Expr:
     """
     Retrieve all children cells of an H3 cell at a specified resolution.
 
     #### Parameters
     - `cell`: IntoExprColumn
         H3 cells as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`.
-    - `resolution`: int (0-15)
+    - `resolution`: int (0-15) | IntoExprColumn
+        Target child resolution, either one value or a per-row column.
+        Rows whose resolution is null or invalid return `None`.
 
     #### Returns
     Expr
@@ -405,13 +414,11 @@ def cell_to_children(cell: IntoExprColumn, resolution: HexResolution) -> pl.Expr
     ... )
     ```
     """
-    assert_valid_resolution(resolution)
     return register_plugin_function(
-        args=[cell],
+        args=[cell, resolution_expr(resolution)],
         plugin_path=LIB,
         function_name="cell_to_children",
         is_elementwise=True,
-        kwargs={"resolution": resolution},
     )
 
 
@@ -515,14 +522,19 @@ def compact_cells(cells: IntoExprColumn) -> pl.Expr:
     )
 
 
-def uncompact_cells(cells: IntoExprColumn, resolution: HexResolution) -> pl.Expr:
+def uncompact_cells(
+    cells: IntoExprColumn,
+    resolution: Union[HexResolution, IntoExprColumn],
+) -> pl.Expr:
     """
     Uncompact a set of H3 cells to the specified resolution.
 
     #### Parameters
     - `cells`: IntoExprColumn
         H3 cells as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`.
-    - `resolution`: int (0-15)
+    - `resolution`: int (0-15) | IntoExprColumn
+        Target resolution. For list columns this may be a per-row column, and rows
+        whose resolution is null or invalid return `None`.
 
     #### Returns
     Expr
@@ -538,11 +550,9 @@ def uncompact_cells(cells: IntoExprColumn, resolution: HexResolution) -> pl.Expr
     ... )
     ```
     """
-    assert_valid_resolution(resolution)
     return register_plugin_function(
-        args=[cells],
+        args=[cells, resolution_expr(resolution)],
         plugin_path=LIB,
         function_name="uncompact_cells",
         is_elementwise=True,
-        kwargs={"resolution": resolution},
     )
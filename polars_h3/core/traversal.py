@@ -1,11 +1,20 @@
 from __future__ import annotations
 
 from pathlib import Path
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Union
 
 import polars as pl
 from polars.plugins import register_plugin_function
 
+from ._types import (
+    AngleUnit,
+    DiskOutput,
+    DistanceMatrixOutput,
+    RingStrictness,
+    SmoothingKernel,
+)
+from .utils import HexResolution, assert_valid_resolution
+
 if TYPE_CHECKING:
     from polars_h3.typing import IntoExprColumn
 
@@ -58,24 +67,103 @@ def grid_distance(origin: IntoExprColumn, destination: IntoExprColumn) -> pl.Exp
     )
 
 
-def grid_ring(cell: IntoExprColumn, k: IntoExprColumn | int) -> pl.Expr:
+def grid_distance_matrix(
+    cells: IntoExprColumn, output: DistanceMatrixOutput = "matrix"
+) -> pl.Expr:
+    """
+    Compute the grid distance between every pair of cells in a list.
+
+    Replaces exploding, self-joining and calling `grid_distance` on the cross
+    product. Each row's list is processed independently and in parallel. Distances
+    are `None` where a cell is null or invalid, or the pair is too far apart or
+    separated by pentagonal distortion.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        `List` column of H3 cells (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `output`: str
+        Layout of the result:
+        - `"matrix"`: `List(List(Int32))`, where item `[i][j]` is the distance from
+          cell `i` to cell `j` (default)
+        - `"long"`: a list of `Struct{origin, destination, distance}`, one per
+          unordered pair of cells in list order
+
+    #### Returns
+    Expr
+        Expression returning the pairwise distances for each list.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "cells": [["8928308280fffff", "8928308280bffff", "89283082813ffff"]]
+    ... })
+    >>> df.select(polars_h3.grid_distance_matrix("cells"))
+    shape: (1, 1)
+    ┌───────────────────────────────────┐
+    │ cells                                 │
+    │ ---                                   │
+    │ list[list[i32]]                       │
+    ╞═══════════════════════════════════╡
+    │ [[0, 1, 2], [1, 0, 2], [2, 2, 0]] │
+    └───────────────────────────────────┘
+    >>> df.select(polars_h3.grid_distance_matrix("cells", output="long")).explode("cells").unnest("cells")
+    shape: (3, 3)
+    ┌─────────────────┬─────────────────┬──────────┐
+    │ origin          │ destination     │ distance │
+    │ ---             │ ---             │ ---      │
+    │ str             │ str             │ i32      │
+    ╞═════════════════╪═════════════════╪══════════╡
+    │ 8928308280fffff │ 8928308280bffff │ 1        │
+    │ 8928308280fffff │ 89283082813ffff │ 2        │
+    │ 8928308280bffff │ 89283082813ffff │ 2        │
+    └─────────────────┴─────────────────┴──────────┘
+    ```
+
+    #### Errors
+    - `ValueError`: If `output` is not recognized.
+    - `ComputeError`: If `cells` is not a `List` column of cells.
+    """
+    if output not in ("matrix", "long"):
+        raise ValueError(f"Invalid output: {output}. Expected one of 'matrix', 'long'")
+    return register_plugin_function(
+        args=[cells],
+        plugin_path=LIB,
+        function_name="grid_distance_matrix"
+        if output == "matrix"
+        else "grid_distance_matrix_long",
+        is_elementwise=True,
+    )
+
+
+def grid_ring(
+    cell: IntoExprColumn,
+    k: IntoExprColumn | int,
+    strict: RingStrictness | None = None,
+) -> pl.Expr:
     """
     Produce a "hollow ring" of cells at exactly grid distance `k` from the origin cell.
 
     For `k=0`, this returns just the origin cell.
     For `k>0`, it returns all cells that are exactly `k` steps away.
 
-    This function may return None items if pentagonal distortion is encountered.
+    The fast ring algorithm fails when the ring runs into a pentagon. By default the
+    ring is then computed from the cells' grid distances, as in `grid_disk_distances`,
+    so no cells are ever dropped.
 
     #### Parameters
     - `cell`: IntoExprColumn
         Column or expression with the H3 cell index (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
     - `k`: IntoExprColumn | int
         The ring distance. Must be non-negative.
+    - `strict`: str | None
+        What to do when the fast algorithm hits pentagon distortion:
+        - `None`: fall back to the distance-based ring (default)
+        - `"raise"`: raise a `ComputeError`
+        - `"null"`: return `None` for that row
 
     #### Returns
     Expr
-        Expression returning a list of H3 cells at distance `k`. May return `None` if pentagonal distortion is encountered.
+        Expression returning a list of H3 cells at distance `k`.
 
     #### Examples
     ```python
@@ -93,7 +181,8 @@ def grid_ring(cell: IntoExprColumn, k: IntoExprColumn | int) -> pl.Expr:
 
     #### Errors
     - `ValueError`: If `k < 0`.
-    - `ComputeError`: If pentagonal distortion or invalid inputs prevent computation.
+    - `ComputeError`: If `strict="raise"` and pentagonal distortion is encountered, or
+      `strict` is not recognized.
     """
     if isinstance(k, int):
         if k < 0:
@@ -105,6 +194,7 @@ def grid_ring(cell: IntoExprColumn, k: IntoExprColumn | int) -> pl.Expr:
         args=[cell, k_expr],
         plugin_path=LIB,
         function_name="grid_ring",
+        kwargs={"strict": strict},
     )
 
 
@@ -158,6 +248,85 @@ def grid_disk(cell: IntoExprColumn, k: IntoExprColumn | int) -> pl.Expr:
     )
 
 
+def grid_disk_distances(
+    cell: IntoExprColumn, k: IntoExprColumn | int, output: DiskOutput = "struct"
+) -> pl.Expr:
+    """
+    Produce the cells within grid distance `k` of the origin cell, with each cell's
+    distance from the origin.
+
+    Computed in one pass like h3's `gridDiskDistances`, so there is no need to call
+    `grid_distance` for every neighbor. Pentagons are handled, and cells are ordered
+    by distance from the origin.
+
+    #### Parameters
+    - `cell`: IntoExprColumn
+        Column or expression with the H3 cell index (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `k`: IntoExprColumn | int
+        The maximum distance from the origin. Must be non-negative.
+    - `output`: str
+        Layout of the result:
+        - `"struct"`: a list of `Struct{cell, k}` (default)
+        - `"rings"`: a list of `k + 1` rings, where item `i` lists the cells at
+          distance `i`. Rings past the edge of a coarse grid are empty lists.
+
+    #### Returns
+    Expr
+        Expression returning the cells within distance `k`, or `None` for invalid cells
+        or a negative per-row `k`.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"input": ["8928308280fffff"]})
+    >>> df.select(polars_h3.grid_disk_distances("input", 1)).explode("input").unnest("input")
+    shape: (7, 2)
+    ┌─────────────────┬─────┐
+    │ cell            │ k   │
+    │ ---             │ --- │
+    │ str             │ u32 │
+    ╞═════════════════╪═════╡
+    │ 8928308280fffff │ 0   │
+    │ 89283082803ffff │ 1   │
+    │ 89283082807ffff │ 1   │
+    │ 8928308280bffff │ 1   │
+    │ 8928308283bffff │ 1   │
+    │ 89283082873ffff │ 1   │
+    │ 89283082877ffff │ 1   │
+    └─────────────────┴─────┘
+    >>> df.select(polars_h3.grid_disk_distances("input", 2, output="rings").list.eval(pl.element().list.len()))
+    shape: (1, 1)
+    ┌────────────┐
+    │ input      │
+    │ ---        │
+    │ list[u32]  │
+    ╞════════════╡
+    │ [1, 6, 12] │
+    └────────────┘
+    ```
+
+    #### Errors
+    - `ValueError`: If `k < 0` or `output` is not recognized.
+    """
+    if output not in ("struct", "rings"):
+        raise ValueError(
+            f"Invalid output: {output}. Expected one of 'struct', 'rings'"
+        )
+    if isinstance(k, int):
+        if k < 0:
+            raise ValueError("k must be non-negative")
+        k_expr = pl.lit(k)
+    else:
+        k_expr = k
+    return register_plugin_function(
+        args=[cell, k_expr],
+        plugin_path=LIB,
+        function_name="grid_disk_distances"
+        if output == "struct"
+        else "grid_disk_distances_rings",
+        is_elementwise=True,
+    )
+
+
 def grid_path_cells(origin: IntoExprColumn, destination: IntoExprColumn) -> pl.Expr:
     """
     Find a minimal contiguous path of cells from `origin` to `destination`.
@@ -205,3 +374,491 @@ def grid_path_cells(origin: IntoExprColumn, destination: IntoExprColumn) -> pl.E
         plugin_path=LIB,
         function_name="grid_path_cells",
     )
+
+
+def grid_path_avoiding(
+    origin: IntoExprColumn,
+    destination: IntoExprColumn,
+    blocked_cells: IntoExprColumn,
+    costs: IntoExprColumn | None = None,
+    max_cells: int = 100_000,
+) -> pl.Expr:
+    """
+    Find the cheapest path of cells from `origin` to `destination` that avoids
+    blocked cells.
+
+    Unlike `grid_path_cells`, which follows a straight line, this runs an A* search
+    over hexagon adjacency, so routes go around obstacles such as water bodies or
+    restricted zones. Entering a cell costs 1 unless `costs` says otherwise.
+
+    #### Parameters
+    - `origin`: IntoExprColumn
+        Column or expression with the starting H3 cell (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `destination`: IntoExprColumn
+        Column or expression with the target H3 cell, at the same resolution.
+    - `blocked_cells`: IntoExprColumn
+        `List` column of cells the path may not enter. A single list, e.g.
+        `pl.lit([...])`, applies to every row.
+    - `costs`: IntoExprColumn | None
+        Optional `List(Struct{cell, cost})` column with the non-negative cost of
+        entering each listed cell. A single list applies to every row, and an
+        infinite cost blocks the cell.
+    - `max_cells`: int
+        Maximum number of cells the search may expand before giving up.
+
+    #### Returns
+    Expr
+        Expression returning `Struct{path, cost}`, with the cells of the path from
+        `origin` to `destination` and its total cost, or `None` if there is no path,
+        the search limit is reached, or the cells are invalid or differ in resolution.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "origin": ["8928308280fffff"],
+    ...     "destination": ["89283082813ffff"],
+    ...     "blocked": [["89283082803ffff"]],
+    ... })
+    >>> df.select(
+    ...     polars_h3.grid_path_avoiding("origin", "destination", "blocked")
+    ... ).unnest("origin")
+    shape: (1, 2)
+    ┌───────────────────────────────────┬──────┐
+    │ path                              ┆ cost │
+    │ ---                               ┆ ---  │
+    │ list[str]                         ┆ f64  │
+    ╞═══════════════════════════════════╪══════╡
+    │ ["8928308280fffff", "89283082807… ┆ 3.0  │
+    └───────────────────────────────────┴──────┘
+    ```
+
+    #### Errors
+    - `ValueError`: If `max_cells < 1`.
+    - `ComputeError`: If `blocked_cells` or `costs` is not a `List` column, a cost is
+      negative, or column lengths do not match.
+    """
+    if max_cells < 1:
+        raise ValueError("max_cells must be at least 1")
+    args = [origin, destination, blocked_cells]
+    if costs is not None:
+        args.append(costs)
+    return register_plugin_function(
+        args=args,
+        plugin_path=LIB,
+        function_name="grid_path_avoiding",
+        is_elementwise=True,
+        kwargs={"max_cells": max_cells},
+    )
+
+
+def cells_connected_components(cells: IntoExprColumn, order: int = 1) -> pl.Expr:
+    """
+    Label the contiguous regions of a set of H3 cells.
+
+    Cells within grid distance `order` of each other belong to the same component,
+    so `order=1` joins hexagons that share an edge. Components are numbered from 0
+    in order of first appearance, and duplicate cells share their component. Cells
+    of different resolutions are never connected.
+
+    If given a `List` column, each row's cells are labelled separately. If given a
+    plain cell column, the whole column is one cell set, so it can be used as a
+    `group_by` aggregation or with `.over()` to label cells within groups.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        Column or expression of H3 cells, or a `List` column of cells (as `pl.UInt64`,
+        `pl.Int64`, or `pl.Utf8`).
+    - `order`: int
+        Maximum grid distance between connected cells. Must be at least 1.
+
+    #### Returns
+    Expr
+        Expression returning a `pl.UInt32` component id per cell (a list of ids for a
+        `List` column), or `None` for null or invalid cells.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "cell": ["8928308280fffff", "8928308280bffff", "89283082813ffff", "85283083fffffff"]
+    ... })
+    >>> df.with_columns(component=polars_h3.cells_connected_components("cell"))
+    shape: (4, 2)
+    ┌─────────────────┬───────────┐
+    │ cell            │ component │
+    │ ---             │ ---       │
+    │ str             │ u32       │
+    ╞═════════════════╪═══════════╡
+    │ 8928308280fffff │ 0         │
+    │ 8928308280bffff │ 0         │
+    │ 89283082813ffff │ 1         │
+    │ 85283083fffffff │ 2         │
+    └─────────────────┴───────────┘
+    >>> # label hotspots within each group
+    >>> df.with_columns(
+    ...     component=polars_h3.cells_connected_components("cell").over("city")
+    ... )
+    ```
+
+    #### Errors
+    - `ValueError`: If `order < 1`.
+    """
+    if order < 1:
+        raise ValueError("order must be at least 1")
+    return register_plugin_function(
+        args=[cells],
+        plugin_path=LIB,
+        function_name="cells_connected_components",
+        kwargs={"order": order},
+    )
+
+
+def cells_dilate(
+    cells: IntoExprColumn, k: IntoExprColumn | int, compact: bool = False
+) -> pl.Expr:
+    """
+    Grow a set of H3 cells by `k` rings.
+
+    Returns every cell within grid distance `k` of a cell in each row's set, such as a
+    service area expanded by a buffer. The set's own cells come first, in order of
+    first appearance, followed by the cells it grows into.
+
+    Duplicate cells are counted once and null or invalid cells are ignored. Cells
+    of different resolutions grow independently.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        `List` column of H3 cells (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `k`: IntoExprColumn | int
+        Number of rings to add. Must be non-negative; a negative `k` in a column
+        gives `None` for that row.
+    - `compact`: bool
+        Compact the result into parent cells where possible (see `compact_cells`).
+
+    #### Returns
+    Expr
+        Expression returning a list of distinct H3 cells per row, or `None` for null
+        lists.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"cell": ["8928308280fffff"]})
+    >>> df = df.select(cells=polars_h3.grid_disk("cell", 1))
+    >>> df.select(polars_h3.cells_dilate("cells", 1).list.len())
+    shape: (1, 1)
+    ┌───────┐
+    │ cells │
+    │ ---   │
+    │ u32   │
+    ╞═══════╡
+    │ 19    │
+    └───────┘
+    >>> # compacted into parent cells where possible
+    >>> df.select(polars_h3.cells_dilate("cells", 1, compact=True))
+    ```
+
+    #### Errors
+    - `ValueError`: If `k < 0`.
+    - `ComputeError`: If `compact=True` and a set mixes resolutions.
+    """
+    if isinstance(k, int):
+        if k < 0:
+            raise ValueError("k must be non-negative")
+        k = pl.lit(k)
+    return register_plugin_function(
+        args=[cells, k],
+        plugin_path=LIB,
+        function_name="cells_dilate",
+        is_elementwise=True,
+        kwargs={"compact": compact},
+    )
+
+
+def cells_erode(
+    cells: IntoExprColumn, k: IntoExprColumn | int, compact: bool = False
+) -> pl.Expr:
+    """
+    Shrink a set of H3 cells by `k` rings.
+
+    Returns the cells of each row's set whose whole `k` disk is in the set, dropping
+    the fringe within `k` rings of its edge. Cells keep their order of first
+    appearance.
+
+    Duplicate cells are counted once and null or invalid cells are ignored. All
+    cells in a set should share a resolution.
+
+    #### Parameters
+    - `cells`: IntoExprColumn
+        `List` column of H3 cells (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `k`: IntoExprColumn | int
+        Number of rings to remove. Must be non-negative; a negative `k` in a column
+        gives `None` for that row.
+    - `compact`: bool
+        Compact the result into parent cells where possible (see `compact_cells`).
+
+    #### Returns
+    Expr
+        Expression returning a list of distinct H3 cells per row, or `None` for null
+        lists.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"cell": ["8928308280fffff"]})
+    >>> df = df.select(cells=polars_h3.grid_disk("cell", 1))
+    >>> df.select(polars_h3.cells_erode("cells", 1).list.len())
+    shape: (1, 1)
+    ┌───────┐
+    │ cells │
+    │ ---   │
+    │ u32   │
+    ╞═══════╡
+    │ 1     │
+    └───────┘
+    >>> # compacted into parent cells where possible
+    >>> df.select(polars_h3.cells_erode("cells", 1, compact=True))
+    ```
+
+    #### Errors
+    - `ValueError`: If `k < 0`.
+    - `ComputeError`: If `compact=True` and a set mixes resolutions.
+    """
+    if isinstance(k, int):
+        if k < 0:
+            raise ValueError("k must be non-negative")
+        k = pl.lit(k)
+    return register_plugin_function(
+        args=[cells, k],
+        plugin_path=LIB,
+        function_name="cells_erode",
+        is_elementwise=True,
+        kwargs={"compact": compact},
+    )
+
+
+def kring_smooth(
+    cell: IntoExprColumn,
+    value: IntoExprColumn,
+    k: IntoExprColumn | int,
+    kernel: SmoothingKernel = "uniform",
+    sigma: float = 1.0,
+) -> pl.Expr:
+    """
+    Average a value over each cell's k-ring, e.g. to smooth a heatmap.
+
+    Each cell gets the weighted mean of the values at cells within grid distance `k`
+    of it, itself included, looked up from the whole column. A cell appearing in
+    several rows counts once per row, and rows with a null value are skipped. Use
+    `.over()` to smooth within groups.
+
+    #### Parameters
+    - `cell`: IntoExprColumn
+        Column or expression of H3 cells (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `value`: IntoExprColumn
+        Numeric column or expression of values, aligned with `cell`.
+    - `k`: IntoExprColumn | int
+        Grid distance of the neighbourhood. Must be non-negative; a negative `k` in a
+        column gives `None` for that row.
+    - `kernel`: SmoothingKernel
+        How neighbours at grid distance `d` are weighted: `"uniform"` (equally),
+        `"inverse_distance"` (`1 / (d + 1)`), or `"gaussian"` (`exp(-d² / 2σ²)`).
+    - `sigma`: float
+        Standard deviation of the `"gaussian"` kernel, in grid distance. Must be
+        positive.
+
+    #### Returns
+    Expr
+        Expression returning the smoothed value as `pl.Float64`, or `None` for null
+        or invalid cells and cells with no values in their neighbourhood.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "cell": ["8928308280fffff", "8928308280bffff", "89283082813ffff"],
+    ...     "value": [10, 4, 1],
+    ... })
+    >>> df.with_columns(
+    ...     smoothed=polars_h3.kring_smooth("cell", "value", 1),
+    ...     inverse_distance=polars_h3.kring_smooth(
+    ...         "cell", "value", 1, kernel="inverse_distance"
+    ...     ),
+    ... )
+    shape: (3, 4)
+    ┌─────────────────┬───────┬──────────┬──────────────────┐
+    │ cell            ┆ value ┆ smoothed ┆ inverse_distance │
+    │ ---             ┆ ---   ┆ ---      ┆ ---              │
+    │ str             ┆ i64   ┆ f64      ┆ f64              │
+    ╞═════════════════╪═══════╪══════════╪══════════════════╡
+    │ 8928308280fffff ┆ 10    ┆ 7.0      ┆ 8.0              │
+    │ 8928308280bffff ┆ 4     ┆ 7.0      ┆ 6.0              │
+    │ 89283082813ffff ┆ 1     ┆ 1.0      ┆ 1.0              │
+    └─────────────────┴───────┴──────────┴──────────────────┘
+    >>> # smooth within each group
+    >>> df.with_columns(
+    ...     smoothed=polars_h3.kring_smooth("cell", "value", 1).over("city")
+    ... )
+    ```
+
+    #### Errors
+    - `ValueError`: If `k < 0`.
+    - `ComputeError`: If `kernel` is not recognised, `sigma` is not positive for the
+      `"gaussian"` kernel, or `value` and `cell` differ in length.
+    """
+    if isinstance(k, int):
+        if k < 0:
+            raise ValueError("k must be non-negative")
+        k = pl.lit(k)
+    return register_plugin_function(
+        args=[cell, value, k],
+        plugin_path=LIB,
+        function_name="kring_smooth",
+        kwargs={"kernel": kernel, "sigma": sigma},
+    )
+
+
+def getis_ord_gi_star(
+    cell: IntoExprColumn, value: IntoExprColumn, k: IntoExprColumn | int
+) -> pl.Expr:
+    """
+    Compute the Getis-Ord Gi* hotspot statistic over each cell's k-ring.
+
+    Treats every row with both a cell and a value as an observation, and the
+    observations within grid distance `k` of a cell, its own included, as its
+    neighbours (binary weights). The z-score compares the neighbourhood's sum of
+    values with what the column's mean and standard deviation predict, so large
+    positive scores mark hotspots and large negative ones coldspots. The p-value is
+    two-sided under the normal approximation. Use `.over()` to test within groups.
+
+    #### Parameters
+    - `cell`: IntoExprColumn
+        Column or expression of H3 cells (as `pl.UInt64`, `pl.Int64`, or `pl.Utf8`).
+    - `value`: IntoExprColumn
+        Numeric column or expression of values, aligned with `cell`.
+    - `k`: IntoExprColumn | int
+        Grid distance of the neighbourhood. Must be non-negative; a negative `k` in a
+        column gives `None` for that row.
+
+    #### Returns
+    Expr
+        Expression returning a struct with `pl.Float64` fields `z_score` and
+        `p_value`, or `None` for null or invalid cells and when the statistic is
+        undefined (e.g. a constant column, or a neighbourhood of every observation).
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "cell": [
+    ...         "8928308280fffff",
+    ...         "8928308280bffff",
+    ...         "89283082873ffff",
+    ...         "89283082813ffff",
+    ...         "8928308281bffff",
+    ...     ],
+    ...     "value": [10, 9, 8, 1, 2],
+    ... })
+    >>> df.with_columns(gi=polars_h3.getis_ord_gi_star("cell", "value", 1)).unnest("gi")
+    shape: (5, 4)
+    ┌─────────────────┬───────┬───────────┬──────────┐
+    │ cell            ┆ value ┆ z_score   ┆ p_value  │
+    │ ---             ┆ ---   ┆ ---       ┆ ---      │
+    │ str             ┆ i64   ┆ f64       ┆ f64      │
+    ╞═════════════════╪═══════╪═══════════╪══════════╡
+    │ 8928308280fffff ┆ 10    ┆ 1.963961  ┆ 0.049535 │
+    │ 8928308280bffff ┆ 9     ┆ 1.336306  ┆ 0.181449 │
+    │ 89283082873ffff ┆ 8     ┆ 1.963961  ┆ 0.049535 │
+    │ 89283082813ffff ┆ 1     ┆ -1.963961 ┆ 0.049535 │
+    │ 8928308281bffff ┆ 2     ┆ -1.309307 ┆ 0.19043  │
+    └─────────────────┴───────┴───────────┴──────────┘
+    >>> # hotspots within each group
+    >>> df.with_columns(gi=polars_h3.getis_ord_gi_star("cell", "value", 1).over("city"))
+    ```
+
+    #### Errors
+    - `ValueError`: If `k < 0`.
+    - `ComputeError`: If `value` and `cell` differ in length.
+    """
+    if isinstance(k, int):
+        if k < 0:
+            raise ValueError("k must be non-negative")
+        k = pl.lit(k)
+    return register_plugin_function(
+        args=[cell, value, k],
+        plugin_path=LIB,
+        function_name="getis_ord_gi_star",
+    )
+
+
+def linestring_to_cells(
+    line: IntoExprColumn,
+    resolution: HexResolution,
+    return_dtype: Union[type[pl.Utf8], type[pl.UInt64], type[pl.Int64]] = pl.UInt64,
+    unit: AngleUnit = "deg",
+) -> pl.Expr:
+    """
+    Find every H3 cell a line passes through, such as a vehicle route.
+
+    Each segment is traced with a grid path between the cells of its endpoints, so
+    the result is a contiguous, ordered sequence of cells. Each cell appears once, at
+    its first visit. A `MULTILINESTRING` covers its parts in order.
+
+    Rows that are null, malformed, or contain a segment that can't be traced (see
+    `grid_path_cells`) return `None`.
+
+    #### Parameters
+    - `line`: IntoExprColumn
+        Column or expression containing either WKT `LINESTRING`/`MULTILINESTRING`
+        strings in lng/lat order, or `List(List(Float64))` coordinates as
+        `[lat, lng]` pairs (the layout used by `cell_to_boundary`).
+    - `resolution`: int (0-15)
+        H3 resolution level of the returned cells.
+    - `return_dtype`: polars.DataType
+        Return type for the H3 indices. `pl.UInt64`, `pl.Int64`, or `pl.Utf8`
+    - `unit`: str
+        Unit of `[lat, lng]` coordinates, `"deg"` (default) or `"rad"`. WKT is always
+        read in degrees.
+
+    #### Returns
+    Expr
+        Expression returning the ordered list of H3 cells along each line.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({
+    ...     "route": ["LINESTRING(-122.418 37.775, -122.405 37.785)"]
+    ... })
+    >>> df.select(polars_h3.linestring_to_cells("route", 9, return_dtype=pl.Utf8))
+    shape: (1, 1)
+    ┌───────────────────────────────────┐
+    │ route                             │
+    │ ---                               │
+    │ list[str]                         │
+    ╞═══════════════════════════════════╡
+    │ ["89283082803ffff", "8928308280b… │
+    └───────────────────────────────────┘
+    ```
+
+    #### Errors
+    - `ValueError`: If `resolution` is invalid.
+    - `ComputeError`: If `unit` is not recognized, or `line` is neither a string nor
+      a list column.
+    """
+    assert_valid_resolution(resolution)
+
+    if return_dtype == pl.Utf8:
+        expr = register_plugin_function(
+            args=[line],
+            plugin_path=LIB,
+            function_name="linestring_to_cells_string",
+            is_elementwise=True,
+            kwargs={"resolution": resolution, "unit": unit},
+        )
+    else:
+        expr = register_plugin_function(
+            args=[line],
+            plugin_path=LIB,
+            function_name="linestring_to_cells",
+            is_elementwise=True,
+            kwargs={"resolution": resolution, "unit": unit},
+        )
+        if return_dtype != pl.UInt64:
+            expr = expr.cast(pl.List(return_dtype))
+
+    return expr
@@ -1,6 +1,55 @@
-from ._types import HexResolution
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Union
+
+import polars as pl
+
+from ._types import CoordinateOutput, HexResolution, PointOutput
+
+if TYPE_CHECKING:
+    from polars_h3.typing import IntoExprColumn
+
+
+POINT_OUTPUTS = ("list", "struct", "geoarrow")
 
 
 def assert_valid_resolution(resolution: HexResolution) -> None:
     if resolution < 0 or resolution > 15:
         raise ValueError("Resolution must be between 0 and 15")
+
+
+def resolution_expr(
+    resolution: Union[HexResolution, IntoExprColumn],
+) -> IntoExprColumn:
+    """
+    Validate a scalar resolution, or pass a per-row resolution column through.
+    """
+    if isinstance(resolution, int):
+        assert_valid_resolution(resolution)
+        return pl.lit(resolution, dtype=pl.UInt8)
+    return resolution
+
+
+def float_expr(value: Union[float, IntoExprColumn]) -> IntoExprColumn:
+    """
+    Wrap a scalar number as a `Float64` literal, or pass a per-row column through.
+    """
+    if isinstance(value, (int, float)):
+        return pl.lit(value, dtype=pl.Float64)
+    return value
+
+
+def coordinate_function(
+    function_name: str,
+    output: Union[CoordinateOutput, PointOutput],
+    allowed: tuple[str, ...] = ("list", "geoarrow"),
+) -> str:
+    """
+    Resolve the plugin function implementing `function_name` for an `output` layout.
+    """
+    if output not in allowed:
+        expected = ", ".join(f"'{name}'" for name in allowed)
+        raise ValueError(f"Invalid output: {output}. Expected one of {expected}")
+    if output == "list":
+        return function_name
+    return f"{function_name}_{output}"
@@ -6,6 +6,9 @@ from typing import TYPE_CHECKING
 import polars as pl
 from polars.plugins import register_plugin_function
 
+from ._types import AngleUnit, PointOutput
+from .utils import POINT_OUTPUTS, coordinate_function
+
 if TYPE_CHECKING:
     from polars_h3.typing import IntoExprColumn
 
@@ -90,7 +93,9 @@ def cell_to_vertexes(cell: IntoExprColumn) -> pl.Expr:
     )
 
 
-def vertex_to_latlng(vertex: IntoExprColumn) -> pl.Expr:
+def vertex_to_latlng(
+    vertex: IntoExprColumn, output: PointOutput = "list", unit: AngleUnit = "deg"
+) -> pl.Expr:
     """
     Convert an H3 vertex index into its latitude and longitude coordinates.
 
@@ -100,10 +105,18 @@ def vertex_to_latlng(vertex: IntoExprColumn) -> pl.Expr:
     #### Parameters
     - `vertex`: IntoExprColumn
         Column or expression containing an H3 vertex index.
+    - `output`: str
+        Coordinate layout of the result:
+        - `"list"`: a `[lat, lng]` list (default)
+        - `"struct"`: `Struct{lat, lng}`
+        - `"geoarrow"`: a GeoArrow point, `Struct{x: lng, y: lat}`
+    - `unit`: str
+        Unit of the returned coordinates, `"deg"` (default) or `"rad"`.
 
     #### Returns
     Expr
-        Expression returning `[latitude, longitude]` as `[f64, f64]`, or `None` if invalid.
+        Expression returning `[latitude, longitude]` as `[f64, f64]` (or the chosen
+        struct layout), or `None` if invalid.
 
     #### Examples
     ```python
@@ -122,7 +135,45 @@ def vertex_to_latlng(vertex: IntoExprColumn) -> pl.Expr:
     return register_plugin_function(
         args=[vertex],
         plugin_path=LIB,
-        function_name="vertex_to_latlng",
+        function_name=coordinate_function("vertex_to_latlng", output, POINT_OUTPUTS),
+        kwargs={"unit": unit},
+    )
+
+
+def vertex_to_latlng_wkb(vertex: IntoExprColumn) -> pl.Expr:
+    """
+    Convert an H3 vertex index into a WKB `Point`.
+
+    The geometry is encoded as little-endian ISO WKB with `x = lng` and `y = lat`.
+    If the vertex is invalid, returns `None`.
+
+    #### Parameters
+    - `vertex`: IntoExprColumn
+        Column or expression containing an H3 vertex index.
+
+    #### Returns
+    Expr
+        Expression returning the vertex point as `pl.Binary`, or `None` if invalid.
+
+    #### Examples
+    ```python
+    >>> df = pl.DataFrame({"vertex": [2459626752788398079]})
+    >>> df.with_columns(point=polars_h3.vertex_to_latlng_wkb("vertex"))
+    shape: (1, 2)
+    ┌──────────────────────┬───────────────────────────────────┐
+    │ vertex               │ point                             │
+    │ ---                  │ ---                               │
+    │ u64                  │ binary                            │
+    ╞══════════════════════╪═══════════════════════════════════╡
+    │ 2459626752788398079  │ b"\x01\x01\x00\x00\x00…          │
+    └──────────────────────┴───────────────────────────────────┘
+    ```
+    """
+    return register_plugin_function(
+        args=[vertex],
+        plugin_path=LIB,
+        function_name="vertex_to_latlng_wkb",
+        is_elementwise=True,
     )
 
 
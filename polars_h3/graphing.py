@@ -1,8 +1,9 @@
+from pathlib import Path
 from typing import Any, Literal, Union
 
 import polars as pl
 
-from .core.indexing import cell_to_boundary
+from .core.indexing import cell_to_boundary, cells_to_geojson
 
 
 def _hex_bounds(
@@ -85,7 +86,9 @@ def plot_hex_outlines(
         df.drop_nulls(subset=[hex_id_col])
         .with_columns(
             [
-                cell_to_boundary(pl.col(hex_id_col)).alias("boundary"),
+                cell_to_boundary(pl.col(hex_id_col), antimeridian="unwrap").alias(
+                    "boundary"
+                ),
             ]
         )
         .filter(pl.col("boundary").is_not_null())
@@ -151,7 +154,9 @@ def plot_hex_fills(
         df.drop_nulls(subset=[hex_id_col, metric_col])
         .with_columns(
             [
-                cell_to_boundary(pl.col(hex_id_col)).alias("boundary"),
+                cell_to_boundary(pl.col(hex_id_col), antimeridian="unwrap").alias(
+                    "boundary"
+                ),
                 pl.col(metric_col).log1p().alias("normalized_metric"),
             ]
         )
@@ -197,3 +202,42 @@ def plot_hex_fills(
     map.fit_bounds(map_bounds)
 
     return map
+
+
+def write_geojson(
+    df: pl.DataFrame,
+    *,
+    hex_id_col: str,
+    property_cols: Union[list[str], None] = None,
+    path: Union[str, Path, None] = None,
+) -> str:
+    """
+    Export H3 cells as a GeoJSON `FeatureCollection` for kepler.gl, Mapbox or QGIS.
+
+    The document is built in Rust with `cells_to_geojson`. Cells crossing the
+    antimeridian are split into `MultiPolygon` features, and null or invalid cells
+    are skipped.
+
+    #### Parameters
+    - `df`: pl.DataFrame
+    - `hex_id_col`: str
+      Column name in `df` holding H3 cell indices.
+    - `property_cols`: list[str] | None, default None
+      Columns written to each feature's `properties`. If None, all columns other than
+      `hex_id_col` are included.
+    - `path`: str | Path | None, default None
+      If given, the GeoJSON is also written to this file.
+
+    #### Returns
+    str
+      The `FeatureCollection` as a JSON string.
+    """
+    if property_cols is None:
+        property_cols = [col for col in df.columns if col != hex_id_col]
+
+    geojson = df.select(cells_to_geojson(hex_id_col, *property_cols)).item()
+
+    if path is not None:
+        Path(path).write_text(geojson, encoding="utf-8")
+
+    return geojson
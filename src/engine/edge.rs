@@ -1,8 +1,14 @@
-use h3o::DirectedEdgeIndex;
+use std::collections::HashSet;
+
+use h3o::{CellIndex, DirectedEdgeIndex};
 use polars::prelude::*;
 use rayon::prelude::*;
+use wkt::ToWkt;
 
-use super::utils::parse_cell_indices;
+use super::utils::{
+    boundary_lines, boundary_list, cast_list_u64_to_dtype, line_geometry, parse_angle_unit,
+    parse_antimeridian, parse_cell_indices, resolve_target_inner_dtype, wkb_lines, AngleUnit,
+};
 
 pub fn are_neighbor_cells(
     origin_series: &Series,
@@ -167,22 +173,141 @@ pub fn origin_to_directed_edges(cell_series: &Series) -> PolarsResult<Series> {
     Ok(edges.into_series())
 }
 
-pub fn directed_edge_to_boundary(edge_series: &Series) -> PolarsResult<Series> {
+pub fn directed_edge_to_boundary(
+    edge_series: &Series,
+    antimeridian: &str,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let mode = parse_antimeridian(antimeridian)?;
+    let unit = parse_angle_unit(unit)?;
     let edges = parse_edge_indices(edge_series)?;
 
     let boundaries: ListChunked = edges
+        .into_par_iter()
+        .map(|edge| {
+            edge.map(|idx| boundary_list(&boundary_lines(&idx.boundary(), mode, unit), mode))
+        })
+        .collect();
+
+    Ok(boundaries.into_series())
+}
+
+pub fn directed_edge_to_boundary_wkt(
+    edge_series: &Series,
+    antimeridian: &str,
+) -> PolarsResult<Series> {
+    let mode = parse_antimeridian(antimeridian)?;
+    let edges = parse_edge_indices(edge_series)?;
+
+    let wkts: StringChunked = edges
         .into_par_iter()
         .map(|edge| {
             edge.map(|idx| {
-                let boundary = idx.boundary();
-                let coords: Vec<f64> = boundary
-                    .iter()
-                    .flat_map(|latlng| vec![latlng.lat(), latlng.lng()])
-                    .collect();
-                Series::new(PlSmallStr::from_str(""), coords.as_slice())
+                line_geometry(boundary_lines(&idx.boundary(), mode, AngleUnit::Degrees))
+                    .wkt_string()
             })
         })
         .collect();
 
-    Ok(boundaries.into_series())
+    Ok(wkts.into_series())
+}
+
+pub fn directed_edge_to_boundary_wkb(
+    edge_series: &Series,
+    antimeridian: &str,
+) -> PolarsResult<Series> {
+    let mode = parse_antimeridian(antimeridian)?;
+    let edges = parse_edge_indices(edge_series)?;
+
+    let wkbs: BinaryChunked = edges
+        .into_par_iter()
+        .map(|edge| {
+            edge.map(|idx| wkb_lines(&boundary_lines(&idx.boundary(), mode, AngleUnit::Degrees)))
+        })
+        .collect();
+
+    Ok(wkbs.into_series())
+}
+
+/// The distinct cells of a set, in order of first appearance, and the directed
+/// edges leading out of the set from each of them.
+fn exposed_edges(cells: Vec<Option<CellIndex>>) -> Vec<(CellIndex, Vec<DirectedEdgeIndex>)> {
+    let members: HashSet<CellIndex> = cells.iter().flatten().copied().collect();
+    let mut seen = HashSet::new();
+    cells
+        .into_iter()
+        .flatten()
+        .filter(|cell| seen.insert(*cell))
+        .map(|cell| {
+            let edges = cell
+                .edges()
+                .filter(|edge| !members.contains(&edge.destination()))
+                .collect();
+            (cell, edges)
+        })
+        .collect()
+}
+
+/// Apply `f` to the exposed edges of each row's cell set.
+fn map_cell_sets<T, F>(list_series: &Series, f: F) -> PolarsResult<Vec<Option<T>>>
+where
+    T: Send,
+    F: Fn(Vec<(CellIndex, Vec<DirectedEdgeIndex>)>) -> T + Sync,
+{
+    let rows: Vec<Option<Series>> = list_series.list()?.into_iter().collect();
+    rows.into_par_iter()
+        .map(|cells| {
+            cells
+                .map(|cells| Ok(f(exposed_edges(parse_cell_indices(&cells)?))))
+                .transpose()
+        })
+        .collect()
+}
+
+fn cell_lists(list_series: &Series, rows: Vec<Option<Vec<u64>>>) -> PolarsResult<Series> {
+    let target_inner_dtype = resolve_target_inner_dtype(list_series.dtype())?;
+    let lists: ListChunked = rows
+        .into_iter()
+        .map(|cells| cells.map(|cells| Series::new(PlSmallStr::from(""), cells.as_slice())))
+        .collect();
+    cast_list_u64_to_dtype(
+        &lists.into_series(),
+        &DataType::UInt64,
+        Some(&target_inner_dtype),
+    )
+}
+
+pub fn cells_boundary(list_series: &Series) -> PolarsResult<Series> {
+    let rows = map_cell_sets(list_series, |exposed| {
+        exposed
+            .into_iter()
+            .filter(|(_, edges)| !edges.is_empty())
+            .map(|(cell, _)| u64::from(cell))
+            .collect()
+    })?;
+    cell_lists(list_series, rows)
+}
+
+pub fn cells_outer_ring(list_series: &Series) -> PolarsResult<Series> {
+    let rows = map_cell_sets(list_series, |exposed| {
+        let mut seen = HashSet::new();
+        exposed
+            .into_iter()
+            .flat_map(|(_, edges)| edges)
+            .map(|edge| edge.destination())
+            .filter(|cell| seen.insert(*cell))
+            .map(u64::from)
+            .collect()
+    })?;
+    cell_lists(list_series, rows)
+}
+
+pub fn cells_perimeter_edges(list_series: &Series) -> PolarsResult<Series> {
+    let rows = map_cell_sets(list_series, |exposed| {
+        exposed
+            .iter()
+            .map(|(_, edges)| edges.len() as u32)
+            .sum::<u32>()
+    })?;
+    Ok(UInt32Chunked::from_iter(rows).into_series())
 }
@@ -3,53 +3,50 @@ use polars::prelude::*;
 use rayon::prelude::*;
 
 use super::utils::{
-    cast_list_u64_to_dtype, cast_u64_to_dtype, parse_cell_indices, resolve_target_inner_dtype,
+    broadcast_resolutions, cast_list_u64_to_dtype, cast_u64_to_dtype, parse_cell_indices,
+    resolve_target_inner_dtype,
 };
 
-fn get_target_resolution(cell: CellIndex, target_res: Option<u8>) -> Option<Resolution> {
-    match target_res {
-        Some(res) => Resolution::try_from(res).ok(),
-        None => {
-            let curr_res = cell.resolution();
-            // Get next resolution if None provided
-            curr_res.succ()
-        },
-    }
+/// Pair each cell with its target resolution, broadcasting a single resolution
+/// or matching a resolution column row by row.
+fn parse_cells_with_resolutions(
+    cell_series: &Series,
+    resolution_series: &Series,
+) -> PolarsResult<Vec<Option<(CellIndex, Resolution)>>> {
+    let cells = parse_cell_indices(cell_series)?;
+    let resolutions = broadcast_resolutions(resolution_series, cells.len())?;
+
+    Ok(cells
+        .into_iter()
+        .zip(resolutions)
+        .map(|(cell, res)| cell.zip(res))
+        .collect())
 }
 
-pub fn cell_to_parent(cell_series: &Series, parent_res: Option<u8>) -> PolarsResult<Series> {
+pub fn cell_to_parent(cell_series: &Series, resolution_series: &Series) -> PolarsResult<Series> {
     let original_dtype = cell_series.dtype().clone();
-    let cells = parse_cell_indices(cell_series)?;
+    let cells = parse_cells_with_resolutions(cell_series, resolution_series)?;
 
     let parents: UInt64Chunked = cells
         .into_par_iter()
-        .map(|cell| {
-            cell.and_then(|idx| {
-                let target_res = match parent_res {
-                    Some(res) => Resolution::try_from(res).ok(),
-                    None => idx.resolution().pred(),
-                };
-                target_res.and_then(|res| idx.parent(res))
-            })
-            .map(Into::into)
-        })
+        .map(|cell| cell.and_then(|(idx, res)| idx.parent(res)).map(Into::into))
         .collect();
 
     cast_u64_to_dtype(&original_dtype, None, parents)
 }
 
-pub fn cell_to_center_child(cell_series: &Series, child_res: Option<u8>) -> PolarsResult<Series> {
+pub fn cell_to_center_child(
+    cell_series: &Series,
+    resolution_series: &Series,
+) -> PolarsResult<Series> {
     let original_dtype = cell_series.dtype().clone();
-    let cells = parse_cell_indices(cell_series)?;
+    let cells = parse_cells_with_resolutions(cell_series, resolution_series)?;
 
     let center_children: UInt64Chunked = cells
         .into_par_iter()
         .map(|cell| {
-            cell.and_then(|idx| {
-                let target_res = get_target_resolution(idx, child_res)?;
-                idx.center_child(target_res)
-            })
-            .map(Into::into)
+            cell.and_then(|(idx, res)| idx.center_child(res))
+                .map(Into::into)
         })
         .collect();
 
@@ -72,34 +69,29 @@ pub fn cell_to_center_child(cell_series: &Series, child_res: Option<u8>) -> Pola
     cast_u64_to_dtype(&original_dtype, Some(&target_dtype), center_children)
 }
 
-pub fn cell_to_children_size(cell_series: &Series, child_res: Option<u8>) -> PolarsResult<Series> {
-    let cells = parse_cell_indices(cell_series)?;
+pub fn cell_to_children_size(
+    cell_series: &Series,
+    resolution_series: &Series,
+) -> PolarsResult<Series> {
+    let cells = parse_cells_with_resolutions(cell_series, resolution_series)?;
 
     let sizes: UInt64Chunked = cells
         .into_par_iter()
-        .map(|cell| {
-            cell.map(|idx| {
-                let target_res = get_target_resolution(idx, child_res)
-                    .unwrap_or_else(|| idx.resolution().succ().unwrap_or(idx.resolution()));
-                idx.children_count(target_res)
-            })
-        })
+        .map(|cell| cell.map(|(idx, res)| idx.children_count(res)))
         .collect();
 
     Ok(sizes.into_series())
 }
 
-pub fn cell_to_children(cell_series: &Series, child_res: Option<u8>) -> PolarsResult<Series> {
+pub fn cell_to_children(cell_series: &Series, resolution_series: &Series) -> PolarsResult<Series> {
     let original_dtype = cell_series.dtype().clone();
-    let cells = parse_cell_indices(cell_series)?;
+    let cells = parse_cells_with_resolutions(cell_series, resolution_series)?;
 
     let children: ListChunked = cells
         .into_par_iter()
         .map(|cell| {
-            cell.map(|idx| {
-                let target_res = get_target_resolution(idx, child_res)
-                    .unwrap_or_else(|| idx.resolution().succ().unwrap_or(idx.resolution()));
-                let children: Vec<u64> = idx.children(target_res).map(Into::into).collect();
+            cell.map(|(idx, res)| {
+                let children: Vec<u64> = idx.children(res).map(Into::into).collect();
                 Series::new(PlSmallStr::from(""), children.as_slice())
             })
         })
@@ -223,41 +215,46 @@ pub fn compact_cells(cell_series: &Series) -> PolarsResult<Series> {
     cast_list_u64_to_dtype(&out_series, &DataType::UInt64, Some(&target_inner_dtype))
 }
 
-pub fn uncompact_cells(cell_series: &Series, res: u8) -> PolarsResult<Series> {
+pub fn uncompact_cells(cell_series: &Series, resolution_series: &Series) -> PolarsResult<Series> {
     let original_dtype = cell_series.dtype().clone();
-    let target_res = Resolution::try_from(res)
-        .map_err(|_| PolarsError::ComputeError("Invalid resolution".into()))?;
 
     // Perform the uncompact logic
     let out_series = if let DataType::List(_) = cell_series.dtype() {
         // Input is already a List column
         let ca = cell_series.list()?;
         let cells_vec: Vec<_> = ca.into_iter().collect();
+        let resolutions = broadcast_resolutions(resolution_series, cells_vec.len())?;
 
         let uncompacted: ListChunked = cells_vec
             .into_par_iter()
-            .map(|opt_series| {
-                opt_series
-                    .map(|series| {
-                        let cells = parse_cell_indices(&series)?;
-                        let cell_vec: Vec<_> = cells.into_iter().flatten().collect();
-
-                        let uncompacted = CellIndex::uncompact(cell_vec, target_res);
-                        // Convert the CellIndex result to a UInt64 Series
-                        let uncompacted_u64: Vec<u64> =
-                            uncompacted.into_iter().map(u64::from).collect();
-                        Ok(Series::new(
-                            PlSmallStr::from(""),
-                            uncompacted_u64.as_slice(),
-                        ))
-                    })
-                    .transpose()
+            .zip(resolutions.into_par_iter())
+            .map(|(opt_series, target_res)| match (opt_series, target_res) {
+                (Some(series), Some(target_res)) => {
+                    let cells = parse_cell_indices(&series)?;
+                    let cell_vec: Vec<_> = cells.into_iter().flatten().collect();
+
+                    let uncompacted = CellIndex::uncompact(cell_vec, target_res);
+                    // Convert the CellIndex result to a UInt64 Series
+                    let uncompacted_u64: Vec<u64> =
+                        uncompacted.into_iter().map(u64::from).collect();
+                    Ok(Some(Series::new(
+                        PlSmallStr::from(""),
+                        uncompacted_u64.as_slice(),
+                    )))
+                },
+                _ => Ok(None),
             })
             .collect::<PolarsResult<_>>()?;
 
         uncompacted.into_series()
     } else {
         // Input is not a list, treat it as a single column of cells.
+        polars_ensure!(
+            resolution_series.len() == 1,
+            ComputeError: "uncompact_cells needs a single resolution when uncompacting a whole column"
+        );
+        let target_res = broadcast_resolutions(resolution_series, 1)?[0]
+            .ok_or_else(|| polars_err!(ComputeError: "Invalid resolution"))?;
         let cells = parse_cell_indices(cell_series)?;
         let cell_vec: Vec<_> = cells.into_iter().flatten().collect();
 
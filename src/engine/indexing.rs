@@ -1,14 +1,27 @@
-use h3o::{CellIndex, LatLng, Resolution};
+use std::collections::{HashSet, VecDeque};
+
+use geo::{Geometry, MultiPolygon, Polygon};
+use h3o::geom::{ContainmentMode, TilerBuilder};
+use h3o::{CellIndex, LatLng, Resolution, EARTH_RADIUS_KM};
 use polars::prelude::*;
 use rayon::prelude::*;
+use serde_json::{json, Map, Value};
+use wkt::{ToWkt, TryFromWkt};
 
-use super::utils::parse_cell_indices;
+use super::utils::{
+    boundary_list, boundary_rings, broadcast_floats, broadcast_resolutions, cast_list_u64_to_dtype,
+    geoarrow_multipolygon, geoarrow_polygon, latlng_geoarrow_points, latlng_lists, latlng_structs,
+    parse_angle_unit, parse_antimeridian, parse_cell_indices, parse_geoarrow_points,
+    parse_latlng_coords, polygon_geometry, wkb_polygons, AngleUnit, Antimeridian,
+};
 
 fn parse_latlng_to_cells(
     lat_series: &Series,
     lng_series: &Series,
-    resolution: u8,
+    resolution_series: &Series,
+    unit: &str,
 ) -> PolarsResult<Vec<Option<CellIndex>>> {
+    let unit = parse_angle_unit(unit)?;
     let lat_vals = match lat_series.dtype() {
         DataType::Float64 => lat_series.f64()?.into_iter().collect::<Vec<_>>(),
         DataType::Float32 => {
@@ -35,17 +48,15 @@ fn parse_latlng_to_cells(
         },
     };
 
-    let resolution = Resolution::try_from(resolution)
-        .map_err(|_| polars_err!(ComputeError: "Invalid resolution: {}", resolution))?;
+    let resolutions = broadcast_resolutions(resolution_series, lat_vals.len())?;
 
     let cells: Vec<Option<CellIndex>> = lat_vals
         .into_par_iter()
         .zip(lng_vals.into_par_iter())
-        .map(|(opt_lat, opt_lng)| match (opt_lat, opt_lng) {
-            (Some(lat), Some(lng)) => LatLng::new(lat, lng)
-                .ok()
-                .map(|coord| coord.to_cell(resolution)),
-            _ => None,
+        .zip(resolutions.into_par_iter())
+        .map(|((lat, lng), resolution)| {
+            let coord = unit.latlng(lat?, lng?)?;
+            Some(coord.to_cell(resolution?))
         })
         .collect();
 
@@ -55,9 +66,10 @@ fn parse_latlng_to_cells(
 pub fn latlng_to_cell(
     lat_series: &Series,
     lng_series: &Series,
-    resolution: u8,
+    resolution_series: &Series,
+    unit: &str,
 ) -> PolarsResult<Series> {
-    let cells = parse_latlng_to_cells(lat_series, lng_series, resolution)?;
+    let cells = parse_latlng_to_cells(lat_series, lng_series, resolution_series, unit)?;
 
     let h3_indices: UInt64Chunked = cells
         .into_par_iter()
@@ -70,9 +82,10 @@ pub fn latlng_to_cell(
 pub fn latlng_to_cell_string(
     lat_series: &Series,
     lng_series: &Series,
-    resolution: u8,
+    resolution_series: &Series,
+    unit: &str,
 ) -> PolarsResult<Series> {
-    let cells = parse_latlng_to_cells(lat_series, lng_series, resolution)?;
+    let cells = parse_latlng_to_cells(lat_series, lng_series, resolution_series, unit)?;
 
     let h3_strings: StringChunked = cells
         .into_par_iter()
@@ -82,69 +95,731 @@ pub fn latlng_to_cell_string(
     Ok(h3_strings.into_series())
 }
 
-pub fn cell_to_lat(cell_series: &Series) -> PolarsResult<Series> {
+pub fn point_to_cell(
+    point_series: &Series,
+    resolution_series: &Series,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let (lat_series, lng_series) = parse_geoarrow_points(point_series)?;
+    latlng_to_cell(&lat_series, &lng_series, resolution_series, unit)
+}
+
+pub fn point_to_cell_string(
+    point_series: &Series,
+    resolution_series: &Series,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let (lat_series, lng_series) = parse_geoarrow_points(point_series)?;
+    latlng_to_cell_string(&lat_series, &lng_series, resolution_series, unit)
+}
+
+pub fn cell_to_lat(cell_series: &Series, unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
     let cells = parse_cell_indices(cell_series)?;
 
     let lats: Float64Chunked = cells
         .into_par_iter()
-        .map(|cell| cell.map(|idx| LatLng::from(idx).lat()))
+        .map(|cell| cell.map(|idx| unit.from_degrees(LatLng::from(idx).lat())))
         .collect();
 
     Ok(lats.into_series())
 }
 
-pub fn cell_to_lng(cell_series: &Series) -> PolarsResult<Series> {
+pub fn cell_to_lng(cell_series: &Series, unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
     let cells = parse_cell_indices(cell_series)?;
 
     let lngs: Float64Chunked = cells
         .into_par_iter()
-        .map(|cell| cell.map(|idx| LatLng::from(idx).lng()))
+        .map(|cell| cell.map(|idx| unit.from_degrees(LatLng::from(idx).lng())))
         .collect();
 
     Ok(lngs.into_series())
 }
 
-pub fn cell_to_latlng(cell_series: &Series) -> PolarsResult<Series> {
+fn cell_centers(cell_series: &Series) -> PolarsResult<Vec<Option<LatLng>>> {
+    let cells = parse_cell_indices(cell_series)?;
+    Ok(cells
+        .into_par_iter()
+        .map(|cell| cell.map(LatLng::from))
+        .collect())
+}
+
+pub fn cell_to_latlng(cell_series: &Series, unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
+    Ok(latlng_lists(&cell_centers(cell_series)?, unit))
+}
+
+pub fn cell_to_latlng_struct(cell_series: &Series, unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
+    latlng_structs(&cell_centers(cell_series)?, unit)
+}
+
+pub fn cell_to_latlng_geoarrow(cell_series: &Series, unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
+    latlng_geoarrow_points(&cell_centers(cell_series)?, unit)
+}
+
+pub fn cell_to_boundary(
+    cell_series: &Series,
+    antimeridian: &str,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let mode = parse_antimeridian(antimeridian)?;
+    let unit = parse_angle_unit(unit)?;
     let cells = parse_cell_indices(cell_series)?;
 
     let coords: ListChunked = cells
         .into_par_iter()
         .map(|cell| {
-            cell.map(|idx| {
-                let latlng = LatLng::from(idx);
-                Series::new(PlSmallStr::from(""), &[latlng.lat(), latlng.lng()])
-            })
+            cell.map(|idx| boundary_list(&boundary_rings(&idx.boundary(), mode, unit), mode))
         })
         .collect();
 
     Ok(coords.into_series())
 }
 
-pub fn cell_to_boundary(cell_series: &Series) -> PolarsResult<Series> {
+pub fn cell_to_boundary_geoarrow(
+    cell_series: &Series,
+    antimeridian: &str,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let mode = parse_antimeridian(antimeridian)?;
+    let unit = parse_angle_unit(unit)?;
     let cells = parse_cell_indices(cell_series)?;
 
-    let coords: ListChunked = cells
+    let polygons: Vec<Option<Series>> = cells
         .into_par_iter()
         .map(|cell| {
             cell.map(|idx| {
-                let boundary = idx.boundary();
+                let rings = boundary_rings(&idx.boundary(), mode, unit);
+                if mode == Antimeridian::Split {
+                    geoarrow_multipolygon(&rings)
+                } else {
+                    geoarrow_polygon(&rings[0])
+                }
+            })
+            .transpose()
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    let polygons: ListChunked = polygons.into_iter().collect();
+    Ok(polygons.into_series())
+}
+
+pub fn cell_to_boundary_wkb(cell_series: &Series, antimeridian: &str) -> PolarsResult<Series> {
+    let mode = parse_antimeridian(antimeridian)?;
+    let cells = parse_cell_indices(cell_series)?;
+
+    let wkbs: BinaryChunked = cells
+        .into_par_iter()
+        .map(|cell| {
+            cell.map(|idx| wkb_polygons(&boundary_rings(&idx.boundary(), mode, AngleUnit::Degrees)))
+        })
+        .collect();
 
-                // Create a Vec<Vec<f64>> for the boundary: each inner vec is [lat, lng]
-                let latlng_pairs: Vec<Vec<f64>> = boundary
-                    .iter()
-                    .map(|vertex| vec![vertex.lat(), vertex.lng()])
-                    .collect();
+    Ok(wkbs.into_series())
+}
 
-                // Convert each [lat, lng] pair into its own Series
-                let inner_series: Vec<Series> = latlng_pairs
-                    .into_iter()
-                    .map(|coords| Series::new(PlSmallStr::from(""), coords))
-                    .collect();
+pub fn cell_to_boundary_wkt(cell_series: &Series, antimeridian: &str) -> PolarsResult<Series> {
+    let mode = parse_antimeridian(antimeridian)?;
+    let cells = parse_cell_indices(cell_series)?;
 
-                Series::new(PlSmallStr::from(""), inner_series)
+    let wkts: StringChunked = cells
+        .into_par_iter()
+        .map(|cell| {
+            cell.map(|idx| {
+                polygon_geometry(boundary_rings(&idx.boundary(), mode, AngleUnit::Degrees))
+                    .wkt_string()
             })
         })
         .collect();
 
-    Ok(coords.into_series())
+    Ok(wkts.into_series())
+}
+
+fn parse_containment_mode(containment: &str) -> PolarsResult<ContainmentMode> {
+    match containment {
+        "centroid" => Ok(ContainmentMode::ContainsCentroid),
+        "contains" => Ok(ContainmentMode::ContainsBoundary),
+        "intersects" => Ok(ContainmentMode::IntersectsBoundary),
+        "covers" => Ok(ContainmentMode::Covers),
+        _ => polars_bail!(
+            ComputeError: "Invalid containment mode: {}. Expected 'centroid', 'contains', 'intersects' or 'covers'",
+            containment
+        ),
+    }
+}
+
+/// Parse a WKT string into a `MultiPolygon`, returning `None` for invalid
+/// WKT or geometries that are not a `POLYGON`/`MULTIPOLYGON`.
+fn parse_wkt_polygon(wkt: &str) -> Option<MultiPolygon> {
+    match Geometry::<f64>::try_from_wkt_str(wkt).ok()? {
+        Geometry::Polygon(polygon) => Some(MultiPolygon::new(vec![polygon])),
+        Geometry::MultiPolygon(multi_polygon) => Some(multi_polygon),
+        _ => None,
+    }
+}
+
+fn polygon_cells(
+    multi_polygon: MultiPolygon,
+    resolution: Resolution,
+    containment: ContainmentMode,
+) -> Option<Vec<u64>> {
+    // Each polygon is tiled on its own: geo's predicates panic on multipolygons
+    // whose members overlap, and the union is the same either way.
+    let mut cells: Vec<u64> = Vec::new();
+    for polygon in multi_polygon {
+        let mut tiler = TilerBuilder::new(resolution)
+            .containment_mode(containment)
+            .build();
+        tiler.add(polygon).ok()?;
+        cells.extend(tiler.into_coverage().map(u64::from));
+    }
+
+    // Overlapping polygons may produce the same cell more than once
+    cells.sort_unstable();
+    cells.dedup();
+    Some(cells)
+}
+
+/// Parse a list of `[lat, lng]` rings into a polygon, the first ring being the
+/// exterior and any others holes.
+fn parse_latlng_rings(rings: &Series, unit: AngleUnit) -> Option<MultiPolygon> {
+    let mut rings = rings
+        .list()
+        .ok()?
+        .into_iter()
+        .map(|ring| parse_latlng_coords(&ring?, unit))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter();
+    let exterior = rings.next()?;
+    Some(MultiPolygon::new(vec![Polygon::new(
+        exterior,
+        rings.collect(),
+    )]))
+}
+
+/// Parse WKT polygons, always in degrees, or `[lat, lng]` rings in `unit`.
+fn parse_polygons(
+    polygon_series: &Series,
+    unit: AngleUnit,
+) -> PolarsResult<Vec<Option<MultiPolygon>>> {
+    let float_list = |depth: usize| {
+        (0..depth).fold(DataType::Float64, |dtype, _| {
+            DataType::List(Box::new(dtype))
+        })
+    };
+
+    Ok(match polygon_series.dtype() {
+        DataType::String => polygon_series
+            .str()?
+            .into_iter()
+            .map(|wkt| wkt.and_then(parse_wkt_polygon))
+            .collect(),
+        // A single exterior ring, as produced by `cell_to_boundary`
+        DataType::List(inner) if !matches!(inner.inner_dtype(), Some(DataType::List(_))) => {
+            polygon_series
+                .cast(&float_list(2))?
+                .list()?
+                .into_iter()
+                .map(|ring| {
+                    let exterior = parse_latlng_coords(&ring?, unit)?;
+                    Some(MultiPolygon::new(vec![Polygon::new(exterior, vec![])]))
+                })
+                .collect()
+        },
+        // An exterior ring followed by holes
+        DataType::List(_) => polygon_series
+            .cast(&float_list(3))?
+            .list()?
+            .into_iter()
+            .map(|rings| parse_latlng_rings(&rings?, unit))
+            .collect(),
+        dtype => polars_bail!(
+            ComputeError: "Unsupported polygon type: {}. Expected WKT strings or List(List(Float64)) rings",
+            dtype
+        ),
+    })
+}
+
+fn parse_polygon_to_cells(
+    polygon_series: &Series,
+    resolution: u8,
+    containment: &str,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let resolution = Resolution::try_from(resolution)
+        .map_err(|_| polars_err!(ComputeError: "Invalid resolution: {}", resolution))?;
+    let containment = parse_containment_mode(containment)?;
+    let unit = parse_angle_unit(unit)?;
+
+    let polygons = parse_polygons(polygon_series, unit)?;
+
+    let cells: ListChunked = polygons
+        .into_par_iter()
+        .map(|multi_polygon| {
+            multi_polygon
+                .and_then(|multi_polygon| polygon_cells(multi_polygon, resolution, containment))
+                .map(|cells| Series::new(PlSmallStr::from(""), cells.as_slice()))
+        })
+        .collect();
+
+    Ok(cells.into_series())
+}
+
+pub fn polygon_to_cells(
+    polygon_series: &Series,
+    resolution: u8,
+    containment: &str,
+    unit: &str,
+) -> PolarsResult<Series> {
+    parse_polygon_to_cells(polygon_series, resolution, containment, unit)
+}
+
+pub fn polygon_to_cells_string(
+    polygon_series: &Series,
+    resolution: u8,
+    containment: &str,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let cells = parse_polygon_to_cells(polygon_series, resolution, containment, unit)?;
+    cast_list_u64_to_dtype(&cells, &DataType::UInt64, Some(&DataType::String))
+}
+
+/// Cells covering a lat/lng bounding box. A box whose `min_lng` is greater
+/// than its `max_lng` crosses the antimeridian and is tiled as two boxes.
+fn bbox_cells(
+    min_lat: f64,
+    min_lng: f64,
+    max_lat: f64,
+    max_lng: f64,
+    resolution: Resolution,
+) -> Option<Vec<u64>> {
+    let valid_lat = |lat: f64| (-90.0..=90.0).contains(&lat);
+    let valid_lng = |lng: f64| (-180.0..=180.0).contains(&lng);
+    if !(valid_lat(min_lat) && valid_lat(max_lat) && valid_lng(min_lng) && valid_lng(max_lng))
+        || min_lat > max_lat
+    {
+        return None;
+    }
+
+    let spans = if min_lng > max_lng {
+        vec![(min_lng, 180.0), (-180.0, max_lng)]
+    } else {
+        vec![(min_lng, max_lng)]
+    };
+
+    let mut cells: Vec<u64> = Vec::new();
+    for (west, east) in spans {
+        let rect = geo::Rect::new((west, min_lat), (east, max_lat));
+        // The boxes never wrap on their own, so h3o must not reinterpret
+        // edges touching +/-180 as crossing the antimeridian.
+        let mut tiler = TilerBuilder::new(resolution)
+            .containment_mode(ContainmentMode::Covers)
+            .disable_transmeridian_heuristic()
+            .build();
+        tiler.add(rect.to_polygon()).ok()?;
+        cells.extend(tiler.into_coverage().map(u64::from));
+    }
+
+    cells.sort_unstable();
+    cells.dedup();
+    Some(cells)
+}
+
+fn parse_bbox_to_cells(inputs: &[Series], unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
+    let len = inputs.iter().map(|s| s.len()).max().unwrap_or(0);
+    let min_lat = broadcast_floats(&inputs[0], len, "min_lat")?;
+    let min_lng = broadcast_floats(&inputs[1], len, "min_lng")?;
+    let max_lat = broadcast_floats(&inputs[2], len, "max_lat")?;
+    let max_lng = broadcast_floats(&inputs[3], len, "max_lng")?;
+    let resolutions = broadcast_resolutions(&inputs[4], len)?;
+
+    let cells: ListChunked = (0..len)
+        .into_par_iter()
+        .map(|i| {
+            bbox_cells(
+                unit.to_degrees(min_lat[i]?),
+                unit.to_degrees(min_lng[i]?),
+                unit.to_degrees(max_lat[i]?),
+                unit.to_degrees(max_lng[i]?),
+                resolutions[i]?,
+            )
+            .map(|cells| Series::new(PlSmallStr::from(""), cells.as_slice()))
+        })
+        .collect();
+
+    Ok(cells.into_series())
+}
+
+pub fn bbox_to_cells(inputs: &[Series], unit: &str) -> PolarsResult<Series> {
+    parse_bbox_to_cells(inputs, unit)
+}
+
+pub fn bbox_to_cells_string(inputs: &[Series], unit: &str) -> PolarsResult<Series> {
+    let cells = parse_bbox_to_cells(inputs, unit)?;
+    cast_list_u64_to_dtype(&cells, &DataType::UInt64, Some(&DataType::String))
+}
+
+/// Whether each point falls inside its row's cell. A point belongs to the cell
+/// `latlng_to_cell` assigns it at that cell's resolution, so cells of any
+/// resolution, including coarser parents, can be checked.
+pub fn cell_contains_latlng(inputs: &[Series], unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
+    let cells = parse_cell_indices(&inputs[0])?;
+    let len = inputs.iter().map(|s| s.len()).max().unwrap_or(0);
+    let cells = match cells.len() {
+        1 => vec![cells[0]; len],
+        n => {
+            polars_ensure!(
+                n == len,
+                ComputeError: "Length of cell ({}) must match input ({})",
+                n,
+                len
+            );
+            cells
+        },
+    };
+    let lats = broadcast_floats(&inputs[1], len, "lat")?;
+    let lngs = broadcast_floats(&inputs[2], len, "lng")?;
+
+    let contains: BooleanChunked = (0..len)
+        .into_par_iter()
+        .map(|i| {
+            let cell = cells[i]?;
+            let coord = unit.latlng(lats[i]?, lngs[i]?)?;
+            Some(coord.to_cell(cell.resolution()) == cell)
+        })
+        .collect();
+
+    Ok(contains.into_series())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CircleMode {
+    Centroid,
+    Any,
+}
+
+fn parse_circle_mode(mode: &str) -> PolarsResult<CircleMode> {
+    match mode {
+        "centroid" => Ok(CircleMode::Centroid),
+        "any" => Ok(CircleMode::Any),
+        _ => polars_bail!(
+            ComputeError: "Invalid circle mode: {}. Expected 'centroid' or 'any'",
+            mode
+        ),
+    }
+}
+
+fn unit_vector(coord: LatLng) -> [f64; 3] {
+    let (lat, lng) = (coord.lat_radians(), coord.lng_radians());
+    [lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin()]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn angle_between(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let c = cross(a, b);
+    dot(c, c).sqrt().atan2(dot(a, b))
+}
+
+/// Angular distance in radians from `p` to the great-circle arc `a`-`b`.
+fn arc_distance(p: [f64; 3], a: [f64; 3], b: [f64; 3]) -> f64 {
+    let normal = cross(a, b);
+    let norm = dot(normal, normal).sqrt();
+    if norm == 0.0 {
+        return angle_between(p, a);
+    }
+    let normal = normal.map(|v| v / norm);
+
+    // `p` projects onto the arc itself when it lies between the planes
+    // through `a` and `b` perpendicular to the arc.
+    let offset = dot(p, normal);
+    let projected = [
+        p[0] - offset * normal[0],
+        p[1] - offset * normal[1],
+        p[2] - offset * normal[2],
+    ];
+    if dot(cross(a, projected), normal) >= 0.0 && dot(cross(projected, b), normal) >= 0.0 {
+        offset.abs().min(1.0).asin()
+    } else {
+        angle_between(p, a).min(angle_between(p, b))
+    }
+}
+
+/// Distance in meters from `center` to the nearest point of `cell`, zero when
+/// `center` lies inside it.
+fn cell_distance_m(cell: CellIndex, center: LatLng, center_cell: CellIndex) -> f64 {
+    if cell == center_cell {
+        return 0.0;
+    }
+    let p = unit_vector(center);
+    let vertices: Vec<[f64; 3]> = cell.boundary().iter().copied().map(unit_vector).collect();
+    let radians = (0..vertices.len())
+        .map(|i| arc_distance(p, vertices[i], vertices[(i + 1) % vertices.len()]))
+        .fold(f64::INFINITY, f64::min);
+    radians * EARTH_RADIUS_KM * 1000.0
+}
+
+/// Rough number of cells at `resolution` within `radius_m` of a point: the
+/// area of the spherical cap over the resolution's average cell area.
+fn estimate_circle_cells(radius_m: f64, resolution: Resolution) -> f64 {
+    let angle = (radius_m / (EARTH_RADIUS_KM * 1000.0)).min(std::f64::consts::PI);
+    let cap_km2 = 2.0 * std::f64::consts::PI * EARTH_RADIUS_KM.powi(2) * (1.0 - angle.cos());
+    cap_km2 / resolution.area_km2()
+}
+
+/// Cells within `radius_m` of `center`. Cells touching the circle form a
+/// connected patch, so they are collected by walking outward from the cell
+/// containing the center, then filtered down for `CircleMode::Centroid`.
+/// Circles expected to cover more than `max_cells` cells are skipped before
+/// walking, since the walk holds every cell it visits.
+fn circle_cells(
+    center: LatLng,
+    radius_m: f64,
+    resolution: Resolution,
+    mode: CircleMode,
+    max_cells: usize,
+) -> Option<Vec<u64>> {
+    if !(radius_m >= 0.0 && radius_m.is_finite())
+        || estimate_circle_cells(radius_m, resolution) > max_cells as f64
+    {
+        return None;
+    }
+
+    let center_cell = center.to_cell(resolution);
+    let mut seen = HashSet::from([center_cell]);
+    let mut queue = VecDeque::from([center_cell]);
+    let mut cells: Vec<u64> = Vec::new();
+
+    while let Some(cell) = queue.pop_front() {
+        let included = match mode {
+            CircleMode::Centroid => LatLng::from(cell).distance_m(center) <= radius_m,
+            CircleMode::Any => true,
+        };
+        if included {
+            cells.push(u64::from(cell));
+        }
+
+        for neighbor in cell.grid_disk::<Vec<_>>(1) {
+            if seen.insert(neighbor) && cell_distance_m(neighbor, center, center_cell) <= radius_m {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    cells.sort_unstable();
+    Some(cells)
+}
+
+fn parse_circle_to_cells(
+    inputs: &[Series],
+    mode: &str,
+    max_cells: usize,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let mode = parse_circle_mode(mode)?;
+    let unit = parse_angle_unit(unit)?;
+    let len = inputs.iter().map(|s| s.len()).max().unwrap_or(0);
+    let lats = broadcast_floats(&inputs[0], len, "lat")?;
+    let lngs = broadcast_floats(&inputs[1], len, "lng")?;
+    let radii = broadcast_floats(&inputs[2], len, "radius_m")?;
+    let resolutions = broadcast_resolutions(&inputs[3], len)?;
+
+    let cells: ListChunked = (0..len)
+        .into_par_iter()
+        .map(|i| {
+            let center = unit.latlng(lats[i]?, lngs[i]?)?;
+            circle_cells(center, radii[i]?, resolutions[i]?, mode, max_cells)
+                .map(|cells| Series::new(PlSmallStr::from(""), cells.as_slice()))
+        })
+        .collect();
+
+    Ok(cells.into_series())
+}
+
+pub fn circle_to_cells(
+    inputs: &[Series],
+    mode: &str,
+    max_cells: usize,
+    unit: &str,
+) -> PolarsResult<Series> {
+    parse_circle_to_cells(inputs, mode, max_cells, unit)
+}
+
+pub fn circle_to_cells_string(
+    inputs: &[Series],
+    mode: &str,
+    max_cells: usize,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let cells = parse_circle_to_cells(inputs, mode, max_cells, unit)?;
+    cast_list_u64_to_dtype(&cells, &DataType::UInt64, Some(&DataType::String))
+}
+
+/// Dissolve a cell set into a WKT outline, or `None` if `dissolve` rejects it,
+/// e.g. because the set mixes resolutions.
+fn dissolve_cells_to_wkt(cells: Vec<Option<CellIndex>>) -> Option<String> {
+    let mut cell_vec: Vec<CellIndex> = cells.into_iter().flatten().collect();
+    // `dissolve` rejects duplicates, which are common after a group_by
+    cell_vec.sort_unstable();
+    cell_vec.dedup();
+
+    h3o::geom::dissolve(cell_vec)
+        .ok()
+        .map(|multi_polygon| multi_polygon.wkt_string())
+}
+
+/// Dissolve each row of a `List` column of cells into its own outline.
+pub fn cells_to_multi_polygon_wkt(cell_series: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        matches!(cell_series.dtype(), DataType::List(_)),
+        ComputeError: "cells_to_multi_polygon_wkt expects a List column of cells, got {}. Use cells_to_multi_polygon_wkt_agg to dissolve a whole column",
+        cell_series.dtype()
+    );
+    let cells_vec: Vec<_> = cell_series.list()?.into_iter().collect();
+
+    let wkts: StringChunked = cells_vec
+        .into_par_iter()
+        .map(|opt_series| {
+            opt_series
+                .map(|series| parse_cell_indices(&series))
+                .transpose()
+                .map(|cells| cells.and_then(dissolve_cells_to_wkt))
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    Ok(wkts.into_series())
+}
+
+/// Dissolve a whole column of cells, or each group in a `group_by`, into a
+/// single outline.
+pub fn cells_to_multi_polygon_wkt_agg(cell_series: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        !matches!(cell_series.dtype(), DataType::List(_)),
+        ComputeError: "cells_to_multi_polygon_wkt_agg expects a column of cells, got {}. Use cells_to_multi_polygon_wkt to dissolve each row of a List column",
+        cell_series.dtype()
+    );
+    let cells = parse_cell_indices(cell_series)?;
+    let wkt = dissolve_cells_to_wkt(cells);
+
+    Ok(StringChunked::from_slice_options(PlSmallStr::from(""), &[wkt]).into_series())
+}
+
+fn series_to_json_values(series: &Series) -> PolarsResult<Vec<Value>> {
+    let values = match series.dtype() {
+        DataType::Boolean => series.bool()?.into_iter().map(Value::from).collect(),
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => series
+            .cast(&DataType::UInt64)?
+            .u64()?
+            .into_iter()
+            .map(Value::from)
+            .collect(),
+        dtype if dtype.is_integer() => series
+            .cast(&DataType::Int64)?
+            .i64()?
+            .into_iter()
+            .map(Value::from)
+            .collect(),
+        // `Value::from` maps NaN and infinities to null, as JSON has no equivalent
+        dtype if dtype.is_float() => series
+            .cast(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .map(Value::from)
+            .collect(),
+        _ => series
+            .cast(&DataType::String)?
+            .str()?
+            .into_iter()
+            .map(Value::from)
+            .collect(),
+    };
+    Ok(values)
+}
+
+fn geojson_ring(ring: Vec<(f64, f64)>) -> Vec<[f64; 2]> {
+    let mut coords: Vec<[f64; 2]> = ring.into_iter().map(|(lng, lat)| [lng, lat]).collect();
+    // GeoJSON rings must be closed
+    if let Some(&first) = coords.first() {
+        coords.push(first);
+    }
+    coords
+}
+
+fn cell_to_geojson_geometry(cell: CellIndex) -> Value {
+    let mut rings: Vec<Vec<[f64; 2]>> =
+        boundary_rings(&cell.boundary(), Antimeridian::Split, AngleUnit::Degrees)
+            .into_iter()
+            .map(geojson_ring)
+            .collect();
+
+    if rings.len() == 1 {
+        json!({ "type": "Polygon", "coordinates": [rings.remove(0)] })
+    } else {
+        let polygons: Vec<_> = rings.into_iter().map(|ring| vec![ring]).collect();
+        json!({ "type": "MultiPolygon", "coordinates": polygons })
+    }
+}
+
+pub fn cells_to_geojson(inputs: &[Series]) -> PolarsResult<Series> {
+    let cell_series = &inputs[0];
+    let property_series = &inputs[1..];
+
+    for series in property_series {
+        polars_ensure!(
+            series.len() == cell_series.len(),
+            ComputeError: "Property column '{}' has length {}, expected {}",
+            series.name(), series.len(), cell_series.len()
+        );
+    }
+
+    let cells = parse_cell_indices(cell_series)?;
+    let names: Vec<String> = property_series
+        .iter()
+        .map(|s| s.name().to_string())
+        .collect();
+    let columns: Vec<Vec<Value>> = property_series
+        .iter()
+        .map(series_to_json_values)
+        .collect::<PolarsResult<_>>()?;
+
+    let features: Vec<Value> = cells
+        .into_par_iter()
+        .enumerate()
+        .filter_map(|(row, cell)| {
+            let cell = cell?;
+            let properties: Map<String, Value> = names
+                .iter()
+                .zip(&columns)
+                .map(|(name, values)| (name.clone(), values[row].clone()))
+                .collect();
+
+            Some(json!({
+                "type": "Feature",
+                "id": cell.to_string(),
+                "geometry": cell_to_geojson_geometry(cell),
+                "properties": properties,
+            }))
+        })
+        .collect();
+
+    let collection = json!({ "type": "FeatureCollection", "features": features });
+    Ok(
+        StringChunked::from_slice(cell_series.name().clone(), &[collection.to_string()])
+            .into_series(),
+    )
 }
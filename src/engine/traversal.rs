@@ -1,9 +1,18 @@
-use h3o::CellIndex;
-use h3o::CoordIJ;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::f64::consts::SQRT_2;
+
+use geo::{Geometry, Line, LineString};
+use h3o::geom::PlotterBuilder;
+use h3o::{CellIndex, CoordIJ, Resolution};
 use polars::prelude::*;
 use rayon::prelude::*;
+use wkt::TryFromWkt;
 
-use super::utils::{cast_list_u64_to_dtype, parse_cell_indices, resolve_target_inner_dtype};
+use super::utils::{
+    cast_list_u64_to_dtype, cast_u64_to_dtype, parse_angle_unit, parse_cell_indices,
+    parse_latlng_coords, resolve_target_inner_dtype,
+};
 
 pub fn grid_distance(origin_series: &Series, destination_series: &Series) -> PolarsResult<Series> {
     let origins = parse_cell_indices(origin_series)?;
@@ -24,76 +33,82 @@ pub fn grid_distance(origin_series: &Series, destination_series: &Series) -> Pol
     Ok(distances.into_series())
 }
 
-pub fn grid_ring(inputs: &[Series]) -> PolarsResult<Series> {
+/// What `grid_ring` does when the fast algorithm runs into pentagon distortion.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RingFallback {
+    /// Compute the ring from the cells' grid distances instead.
+    Distances,
+    /// Fail the whole expression.
+    Raise,
+    /// Return null for the row.
+    Null,
+}
+
+fn parse_ring_fallback(strict: Option<&str>) -> PolarsResult<RingFallback> {
+    match strict {
+        None => Ok(RingFallback::Distances),
+        Some("raise") => Ok(RingFallback::Raise),
+        Some("null") => Ok(RingFallback::Null),
+        Some(strict) => polars_bail!(
+            ComputeError: "Invalid strict mode: {}. Expected 'raise' or 'null'",
+            strict
+        ),
+    }
+}
+
+/// Cells at exactly grid distance `k` from `cell`. `grid_ring_fast` yields
+/// `None` for cells it cannot reach around a pentagon, so rather than dropping
+/// those the ring is either rebuilt from `grid_disk_distances` or rejected.
+fn ring_cells(cell: CellIndex, k: u32, fallback: RingFallback) -> PolarsResult<Option<Vec<u64>>> {
+    if let Some(ring) = cell
+        .grid_ring_fast(k)
+        .map(|cell| cell.map(u64::from))
+        .collect::<Option<Vec<_>>>()
+    {
+        return Ok(Some(ring));
+    }
+
+    match fallback {
+        RingFallback::Distances => Ok(Some(
+            disk_distances(cell, k)
+                .into_iter()
+                .filter(|&(_, distance)| distance == k)
+                .map(|(cell, _)| u64::from(cell))
+                .collect(),
+        )),
+        RingFallback::Raise => polars_bail!(
+            ComputeError: "Pentagon distortion in grid ring of {} at k = {}",
+            cell,
+            k
+        ),
+        RingFallback::Null => Ok(None),
+    }
+}
+
+pub fn grid_ring(inputs: &[Series], strict: Option<&str>) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
     let k_series = &inputs[1];
+    let fallback = parse_ring_fallback(strict)?;
 
-    let cells = super::utils::parse_cell_indices(cell_series)?;
-    let k_cast = k_series.cast(&DataType::Int32)?;
-    let k_i32 = k_cast.i32()?;
+    let cells = parse_cell_indices(cell_series)?;
+    let ks = broadcast_k(k_series, cells.len())?;
 
-    let cells_vec: Vec<_> = cells.into_iter().collect();
-    let is_scalar_k = k_series.len() == 1 && !matches!(k_series.dtype(), DataType::List(_));
-    let ring_results: Vec<Option<Vec<u64>>> = if is_scalar_k {
-        // Scalar case: broadcast the single k value across all cells
-        let k_val = k_i32
-            .get(0)
-            .ok_or_else(|| polars_err!(ComputeError: "k_series is empty or invalid"))?;
-        if k_val < 0 {
-            return Err(polars_err!(ComputeError: "k must be non-negative"));
-        }
-        cells_vec
-            .into_par_iter()
-            .map(|maybe_cell| match maybe_cell {
-                Some(cell) => {
-                    let k_u32 = k_val as u32;
-                    Some(
-                        cell.grid_ring_fast(k_u32)
-                            .flatten()
-                            .map(Into::into)
-                            .collect(),
-                    )
-                },
-                _ => None,
-            })
-            .collect()
-    } else {
-        // Column case: zip with k values
-        let k_vec: Vec<_> = k_i32.into_iter().collect();
-        if k_vec.len() != cells_vec.len() {
-            return Err(polars_err!(
-                ComputeError: "Length of k_series ({}) must match cell_series ({})",
-                k_vec.len(),
-                cells_vec.len()
-            ));
-        }
-        cells_vec
-            .into_par_iter()
-            .zip(k_vec.into_par_iter())
-            .map(|(maybe_cell, maybe_k)| match (maybe_cell, maybe_k) {
-                (Some(cell), Some(k_val)) if k_val >= 0 => {
-                    let k_u32 = k_val as u32;
-                    Some(
-                        cell.grid_ring_fast(k_u32)
-                            .flatten()
-                            .map(Into::into)
-                            .collect(),
-                    )
-                },
-                _ => None,
-            })
-            .collect()
-    };
+    let ring_results: Vec<Option<Vec<u64>>> = cells
+        .into_par_iter()
+        .zip(ks.into_par_iter())
+        .map(|(cell, k)| match (cell, k) {
+            (Some(cell), Some(k)) => ring_cells(cell, k, fallback),
+            _ => Ok(None),
+        })
+        .collect::<PolarsResult<_>>()?;
 
     let rings: ListChunked = ring_results
         .into_iter()
         .map(|opt| opt.map(|rings| Series::new(PlSmallStr::from(""), rings.as_slice())))
-        .collect::<Vec<_>>()
-        .into_iter()
         .collect();
 
-    let target_inner_dtype = super::utils::resolve_target_inner_dtype(cell_series.dtype())?;
-    super::utils::cast_list_u64_to_dtype(
+    let target_inner_dtype = resolve_target_inner_dtype(cell_series.dtype())?;
+    cast_list_u64_to_dtype(
         &rings.into_series(),
         &DataType::UInt64,
         Some(&target_inner_dtype),
@@ -176,6 +191,583 @@ pub fn grid_disk(inputs: &[Series]) -> PolarsResult<Series> {
     cast_list_u64_to_dtype(&disks_series, &DataType::UInt64, Some(&target_inner_dtype))
 }
 
+/// Parse a `k` argument like `grid_disk`: a single value is broadcast to every
+/// row, a column is matched row by row, and negative values become null. Null
+/// and negative values are never an error, since a one-row column looks just
+/// like a literal here; literal `k` is validated on the Python side.
+fn broadcast_k(k_series: &Series, len: usize) -> PolarsResult<Vec<Option<u32>>> {
+    let k_cast = k_series.cast(&DataType::Int32)?;
+    let k_vec: Vec<Option<u32>> = k_cast
+        .i32()?
+        .into_iter()
+        .map(|k| k.and_then(|k| u32::try_from(k).ok()))
+        .collect();
+
+    if k_vec.len() == 1 {
+        return Ok(vec![k_vec[0]; len]);
+    }
+
+    polars_ensure!(
+        k_vec.len() == len,
+        ComputeError: "k_series length ({}) must match cell_series length ({})",
+        k_vec.len(),
+        len
+    );
+    Ok(k_vec)
+}
+
+/// Cells paired with their grid distance from an origin.
+type DiskDistances = Vec<(CellIndex, u32)>;
+
+/// Cells within `k` of `cell` with their grid distance, nearest first. Like
+/// h3's `gridDiskDistances`, the fast algorithm is tried first and the safe one
+/// is used when it runs into a pentagon.
+fn disk_distances(cell: CellIndex, k: u32) -> DiskDistances {
+    let mut cells: DiskDistances = cell.grid_disk_distances(k);
+    cells.sort_by_key(|&(cell, distance)| (distance, u64::from(cell)));
+    cells
+}
+
+/// Each row's disk along with the `k` it was requested with.
+fn parse_disk_distances(inputs: &[Series]) -> PolarsResult<Vec<Option<(DiskDistances, u32)>>> {
+    let cells = parse_cell_indices(&inputs[0])?;
+    let ks = broadcast_k(&inputs[1], cells.len())?;
+
+    Ok(cells
+        .into_par_iter()
+        .zip(ks.into_par_iter())
+        .map(|(cell, k)| Some((disk_distances(cell?, k?), k?)))
+        .collect())
+}
+
+pub fn grid_disk_distances(inputs: &[Series]) -> PolarsResult<Series> {
+    let target_inner_dtype = resolve_target_inner_dtype(inputs[0].dtype())?;
+    let disks = parse_disk_distances(inputs)?;
+
+    let rows: Vec<Option<Series>> = disks
+        .into_par_iter()
+        .map(|disk| {
+            disk.map(|(disk, _)| {
+                let cells: UInt64Chunked = disk
+                    .iter()
+                    .map(|&(cell, _)| Some(u64::from(cell)))
+                    .collect();
+                let cells = cast_u64_to_dtype(&DataType::UInt64, Some(&target_inner_dtype), cells)?
+                    .with_name(PlSmallStr::from("cell"));
+                let ks = Series::new(
+                    PlSmallStr::from("k"),
+                    disk.iter().map(|&(_, k)| k).collect::<Vec<_>>(),
+                );
+                Ok(StructChunked::from_series(
+                    PlSmallStr::from(""),
+                    cells.len(),
+                    [cells, ks].iter(),
+                )?
+                .into_series())
+            })
+            .transpose()
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    let disks: ListChunked = rows.into_iter().collect();
+    disks
+        .into_series()
+        .cast(&grid_disk_distances_dtype(&target_inner_dtype))
+}
+
+pub fn grid_disk_distances_rings(inputs: &[Series]) -> PolarsResult<Series> {
+    let target_inner_dtype = resolve_target_inner_dtype(inputs[0].dtype())?;
+    let disks = parse_disk_distances(inputs)?;
+
+    let rows: Vec<Option<Series>> = disks
+        .into_par_iter()
+        .map(|disk| {
+            // Every row has `k + 1` rings, even when a small grid runs out of
+            // cells before reaching distance `k`
+            disk.map(|(disk, k)| {
+                let rings: ListChunked = (0..=k)
+                    .map(|ring| {
+                        let cells: Vec<u64> = disk
+                            .iter()
+                            .filter(|&&(_, distance)| distance == ring)
+                            .map(|&(cell, _)| u64::from(cell))
+                            .collect();
+                        Some(Series::new(PlSmallStr::from(""), cells.as_slice()))
+                    })
+                    .collect();
+                cast_list_u64_to_dtype(
+                    &rings.into_series(),
+                    &DataType::UInt64,
+                    Some(&target_inner_dtype),
+                )
+            })
+            .transpose()
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    let rings: ListChunked = rows.into_iter().collect();
+    rings
+        .into_series()
+        .cast(&DataType::List(Box::new(DataType::List(Box::new(
+            target_inner_dtype,
+        )))))
+}
+
+/// `List(Struct{cell, k})`, with `cell` in the input's cell dtype.
+pub fn grid_disk_distances_dtype(cell_dtype: &DataType) -> DataType {
+    DataType::List(Box::new(DataType::Struct(vec![
+        Field::new(PlSmallStr::from("cell"), cell_dtype.clone()),
+        Field::new(PlSmallStr::from("k"), DataType::UInt32),
+    ])))
+}
+
+/// Parse a `List` column of cells into per-row cell lists, keeping null or
+/// invalid cells as `None` so results stay aligned with the input.
+fn parse_cell_lists(list_series: &Series) -> PolarsResult<Vec<Option<Vec<Option<CellIndex>>>>> {
+    list_series
+        .list()?
+        .into_iter()
+        .map(|cells| cells.map(|cells| parse_cell_indices(&cells)).transpose())
+        .collect()
+}
+
+fn pairwise_distance(a: Option<CellIndex>, b: Option<CellIndex>) -> Option<i32> {
+    a?.grid_distance(b?).ok()
+}
+
+pub fn grid_distance_matrix(list_series: &Series) -> PolarsResult<Series> {
+    let rows = parse_cell_lists(list_series)?;
+
+    let matrices: Vec<Option<Series>> = rows
+        .into_par_iter()
+        .map(|cells| {
+            cells.map(|cells| {
+                let matrix: ListChunked = cells
+                    .iter()
+                    .map(|&a| {
+                        let distances: Int32Chunked =
+                            cells.iter().map(|&b| pairwise_distance(a, b)).collect();
+                        Some(distances.into_series())
+                    })
+                    .collect();
+                matrix.into_series()
+            })
+        })
+        .collect();
+
+    let matrices: ListChunked = matrices.into_iter().collect();
+    matrices
+        .into_series()
+        .cast(&DataType::List(Box::new(DataType::List(Box::new(
+            DataType::Int32,
+        )))))
+}
+
+pub fn grid_distance_matrix_long(list_series: &Series) -> PolarsResult<Series> {
+    let target_inner_dtype = resolve_target_inner_dtype(list_series.dtype())?;
+    let rows = parse_cell_lists(list_series)?;
+
+    let pairs: Vec<Option<Series>> = rows
+        .into_par_iter()
+        .map(|cells| {
+            cells
+                .map(|cells| {
+                    // Each unordered pair once, in the order of the input list
+                    let pairs: Vec<(usize, usize)> = (0..cells.len())
+                        .flat_map(|i| (i + 1..cells.len()).map(move |j| (i, j)))
+                        .collect();
+                    let cell_column = |name: &str, index: fn(&(usize, usize)) -> usize| {
+                        let values: UInt64Chunked = pairs
+                            .iter()
+                            .map(|pair| cells[index(pair)].map(u64::from))
+                            .collect();
+                        cast_u64_to_dtype(&DataType::UInt64, Some(&target_inner_dtype), values)
+                            .map(|series| series.with_name(PlSmallStr::from(name)))
+                    };
+                    let origins = cell_column("origin", |&(i, _)| i)?;
+                    let destinations = cell_column("destination", |&(_, j)| j)?;
+                    let distances: Int32Chunked = pairs
+                        .iter()
+                        .map(|&(i, j)| pairwise_distance(cells[i], cells[j]))
+                        .collect();
+                    let distances = distances
+                        .into_series()
+                        .with_name(PlSmallStr::from("distance"));
+
+                    Ok(StructChunked::from_series(
+                        PlSmallStr::from(""),
+                        pairs.len(),
+                        [origins, destinations, distances].iter(),
+                    )?
+                    .into_series())
+                })
+                .transpose()
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    let pairs: ListChunked = pairs.into_iter().collect();
+    pairs
+        .into_series()
+        .cast(&grid_distance_pairs_dtype(&target_inner_dtype))
+}
+
+/// `List(Struct{origin, destination, distance})`, with the cells in the input's
+/// cell dtype.
+pub fn grid_distance_pairs_dtype(cell_dtype: &DataType) -> DataType {
+    DataType::List(Box::new(DataType::Struct(vec![
+        Field::new(PlSmallStr::from("origin"), cell_dtype.clone()),
+        Field::new(PlSmallStr::from("destination"), cell_dtype.clone()),
+        Field::new(PlSmallStr::from("distance"), DataType::Int32),
+    ])))
+}
+
+/// Find the root of `i`, halving paths along the way.
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// Label contiguous groups of cells, where cells within grid distance `order`
+/// of each other are connected. Components are numbered from 0 in order of
+/// first appearance, and duplicate cells share their component.
+fn connected_components(cells: &[Option<CellIndex>], order: u32) -> Vec<Option<u32>> {
+    let mut positions: HashMap<CellIndex, usize> = HashMap::new();
+    for cell in cells.iter().flatten() {
+        let next = positions.len();
+        positions.entry(*cell).or_insert(next);
+    }
+
+    let mut parents: Vec<usize> = (0..positions.len()).collect();
+    for (&cell, &i) in &positions {
+        for neighbor in cell.grid_disk::<Vec<_>>(order) {
+            if let Some(&j) = positions.get(&neighbor) {
+                let (root_i, root_j) = (find_root(&mut parents, i), find_root(&mut parents, j));
+                if root_i != root_j {
+                    parents[root_i.max(root_j)] = root_i.min(root_j);
+                }
+            }
+        }
+    }
+
+    let mut labels: HashMap<usize, u32> = HashMap::new();
+    cells
+        .iter()
+        .map(|cell| {
+            let root = find_root(&mut parents, positions[cell.as_ref()?]);
+            let next = labels.len() as u32;
+            Some(*labels.entry(root).or_insert(next))
+        })
+        .collect()
+}
+
+pub fn cells_connected_components(cell_series: &Series, order: u32) -> PolarsResult<Series> {
+    if let DataType::List(_) = cell_series.dtype() {
+        // Input is a List column: label each row's cell set
+        let rows: Vec<Option<Series>> = cell_series.list()?.into_iter().collect();
+        let components: Vec<Option<Series>> = rows
+            .into_par_iter()
+            .map(|cells| {
+                cells
+                    .map(|cells| {
+                        let labels = connected_components(&parse_cell_indices(&cells)?, order);
+                        Ok(UInt32Chunked::from_iter(labels).into_series())
+                    })
+                    .transpose()
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        let components: ListChunked = components.into_iter().collect();
+        components
+            .into_series()
+            .cast(&DataType::List(Box::new(DataType::UInt32)))
+    } else {
+        // Input is not a list, so the whole column is one cell set
+        let cells = parse_cell_indices(cell_series)?;
+        Ok(UInt32Chunked::from_iter(connected_components(&cells, order)).into_series())
+    }
+}
+
+/// Distinct cells of a set in order of first appearance, with a lookup set.
+fn distinct_cells(cells: Vec<Option<CellIndex>>) -> (Vec<CellIndex>, HashSet<CellIndex>) {
+    let mut members = HashSet::new();
+    let cells = cells
+        .into_iter()
+        .flatten()
+        .filter(|cell| members.insert(*cell))
+        .collect();
+    (cells, members)
+}
+
+/// Cells of a set with a neighbor outside it. Only these need a disk when
+/// growing or shrinking the set: a path leaving the set always passes through
+/// one, so any cell within `k` of an interior cell is either in the set or
+/// within `k` of a boundary cell.
+fn boundary_cells<'a>(
+    cells: &'a [CellIndex],
+    members: &'a HashSet<CellIndex>,
+) -> impl Iterator<Item = CellIndex> + 'a {
+    cells.iter().copied().filter(|cell| {
+        cell.grid_disk::<Vec<_>>(1)
+            .iter()
+            .any(|neighbor| !members.contains(neighbor))
+    })
+}
+
+/// Cells within grid distance `k` of the set: the set itself, followed by the
+/// cells it grows into.
+fn dilate(cells: Vec<Option<CellIndex>>, k: u32) -> Vec<CellIndex> {
+    let (mut cells, mut members) = distinct_cells(cells);
+    let boundary: Vec<CellIndex> = boundary_cells(&cells, &members).collect();
+    for cell in boundary {
+        for neighbor in cell.grid_disk::<Vec<_>>(k) {
+            if members.insert(neighbor) {
+                cells.push(neighbor);
+            }
+        }
+    }
+    cells
+}
+
+/// Cells of the set whose whole `k` disk is in the set, i.e. those more than
+/// `k - 1` away from every boundary cell.
+fn erode(cells: Vec<Option<CellIndex>>, k: u32) -> Vec<CellIndex> {
+    let (cells, members) = distinct_cells(cells);
+    if k == 0 {
+        return cells;
+    }
+    let removed: HashSet<CellIndex> = boundary_cells(&cells, &members)
+        .flat_map(|cell| cell.grid_disk::<Vec<_>>(k - 1))
+        .collect();
+    cells
+        .into_iter()
+        .filter(|cell| !removed.contains(cell))
+        .collect()
+}
+
+fn morph_cell_sets(
+    inputs: &[Series],
+    compact: bool,
+    op: fn(Vec<Option<CellIndex>>, u32) -> Vec<CellIndex>,
+) -> PolarsResult<Series> {
+    let list_series = &inputs[0];
+    let target_inner_dtype = resolve_target_inner_dtype(list_series.dtype())?;
+    let rows: Vec<Option<Series>> = list_series.list()?.into_iter().collect();
+    let ks = broadcast_k(&inputs[1], rows.len())?;
+
+    let results: Vec<Option<Vec<u64>>> = rows
+        .into_par_iter()
+        .zip(ks.into_par_iter())
+        .map(|(cells, k)| match (cells, k) {
+            (Some(cells), Some(k)) => {
+                let cells = op(parse_cell_indices(&cells)?, k);
+                let cells = if compact {
+                    CellIndex::compact(cells)
+                        .map_err(|e| polars_err!(ComputeError: "Compaction error: {}", e))?
+                        .collect()
+                } else {
+                    cells
+                };
+                Ok(Some(cells.into_iter().map(u64::from).collect()))
+            },
+            _ => Ok(None),
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    let sets: ListChunked = results
+        .into_iter()
+        .map(|opt| opt.map(|cells| Series::new(PlSmallStr::from(""), cells.as_slice())))
+        .collect();
+    cast_list_u64_to_dtype(
+        &sets.into_series(),
+        &DataType::UInt64,
+        Some(&target_inner_dtype),
+    )
+}
+
+pub fn cells_dilate(inputs: &[Series], compact: bool) -> PolarsResult<Series> {
+    morph_cell_sets(inputs, compact, dilate)
+}
+
+pub fn cells_erode(inputs: &[Series], compact: bool) -> PolarsResult<Series> {
+    morph_cell_sets(inputs, compact, erode)
+}
+
+/// How `kring_smooth` weights a neighbor at grid distance `d`.
+#[derive(Clone, Copy, PartialEq)]
+enum SmoothingKernel {
+    /// Every neighbor counts equally.
+    Uniform,
+    /// `1 / (d + 1)`, so the cell itself has weight 1.
+    InverseDistance,
+    /// `exp(-d² / 2σ²)` for the given `σ`.
+    Gaussian(f64),
+}
+
+impl SmoothingKernel {
+    fn weight(self, distance: u32) -> f64 {
+        let distance = f64::from(distance);
+        match self {
+            SmoothingKernel::Uniform => 1.0,
+            SmoothingKernel::InverseDistance => 1.0 / (distance + 1.0),
+            SmoothingKernel::Gaussian(sigma) => {
+                (-distance * distance / (2.0 * sigma * sigma)).exp()
+            },
+        }
+    }
+}
+
+fn parse_smoothing_kernel(kernel: &str, sigma: f64) -> PolarsResult<SmoothingKernel> {
+    match kernel {
+        "uniform" => Ok(SmoothingKernel::Uniform),
+        "inverse_distance" => Ok(SmoothingKernel::InverseDistance),
+        "gaussian" => {
+            polars_ensure!(sigma > 0.0, ComputeError: "sigma must be positive, got {}", sigma);
+            Ok(SmoothingKernel::Gaussian(sigma))
+        },
+        _ => polars_bail!(
+            ComputeError: "Invalid kernel: {}. Expected 'uniform', 'inverse_distance' or 'gaussian'",
+            kernel
+        ),
+    }
+}
+
+/// Parse a numeric value column that must line up with a cell column of `len`
+/// rows.
+fn parse_values(value_series: &Series, len: usize) -> PolarsResult<Vec<Option<f64>>> {
+    let values: Vec<Option<f64>> = value_series
+        .cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
+        .collect();
+    polars_ensure!(
+        values.len() == len,
+        ComputeError: "value length ({}) must match cell length ({})",
+        values.len(),
+        len
+    );
+    Ok(values)
+}
+
+/// Sum and count of the non-null values at each cell, so that a cell appearing
+/// in several rows counts once per row.
+fn values_by_cell(
+    cells: &[Option<CellIndex>],
+    values: &[Option<f64>],
+) -> HashMap<CellIndex, (f64, u32)> {
+    let mut by_cell: HashMap<CellIndex, (f64, u32)> = HashMap::new();
+    for (cell, value) in cells.iter().zip(values) {
+        if let (Some(cell), Some(value)) = (cell, value) {
+            let entry = by_cell.entry(*cell).or_default();
+            entry.0 += value;
+            entry.1 += 1;
+        }
+    }
+    by_cell
+}
+
+pub fn kring_smooth(inputs: &[Series], kernel: &str, sigma: f64) -> PolarsResult<Series> {
+    let kernel = parse_smoothing_kernel(kernel, sigma)?;
+    let cells = parse_cell_indices(&inputs[0])?;
+    let values = parse_values(&inputs[1], cells.len())?;
+    let ks = broadcast_k(&inputs[2], cells.len())?;
+    let by_cell = values_by_cell(&cells, &values);
+
+    let smoothed: Float64Chunked = cells
+        .into_par_iter()
+        .zip(ks.into_par_iter())
+        .map(|(cell, k)| {
+            let (mut total, mut weights) = (0.0, 0.0);
+            for (neighbor, distance) in cell?.grid_disk_distances::<Vec<_>>(k?) {
+                if let Some(&(sum, count)) = by_cell.get(&neighbor) {
+                    let weight = kernel.weight(distance);
+                    total += weight * sum;
+                    weights += weight * f64::from(count);
+                }
+            }
+            (weights > 0.0).then(|| total / weights)
+        })
+        .collect();
+
+    Ok(smoothed.into_series())
+}
+
+/// Getis-Ord Gi* with binary weights: every observation within grid distance
+/// `k` of a cell, the cell's own included, is a neighbor. Returns the z-score
+/// and its two-sided p-value per row.
+pub fn getis_ord_gi_star(inputs: &[Series]) -> PolarsResult<Series> {
+    let cells = parse_cell_indices(&inputs[0])?;
+    let values = parse_values(&inputs[1], cells.len())?;
+    let ks = broadcast_k(&inputs[2], cells.len())?;
+    let by_cell = values_by_cell(&cells, &values);
+
+    // Every row with both a cell and a value is an observation
+    let observed: Vec<f64> = cells
+        .iter()
+        .zip(&values)
+        .filter_map(|(cell, value)| cell.and(*value))
+        .collect();
+    let n = observed.len() as f64;
+    let mean = observed.iter().sum::<f64>() / n;
+    let std = (observed.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+
+    let results: Vec<Option<(f64, f64)>> = cells
+        .into_par_iter()
+        .zip(ks.into_par_iter())
+        .map(|(cell, k)| {
+            let (mut total, mut weights) = (0.0, 0.0);
+            for neighbor in cell?.grid_disk::<Vec<_>>(k?) {
+                if let Some(&(sum, count)) = by_cell.get(&neighbor) {
+                    total += sum;
+                    weights += f64::from(count);
+                }
+            }
+            // With binary weights the sum of squared weights is the sum of weights
+            let spread = std * ((n * weights - weights * weights) / (n - 1.0)).sqrt();
+            let z_score = (total - mean * weights) / spread;
+            // A constant column or a neighborhood of every observation has no spread
+            z_score
+                .is_finite()
+                .then(|| (z_score, libm::erfc(z_score.abs() / SQRT_2)))
+        })
+        .collect();
+
+    let z_scores: Float64Chunked = results
+        .iter()
+        .map(|result| result.map(|(z_score, _)| z_score))
+        .collect();
+    let p_values: Float64Chunked = results
+        .iter()
+        .map(|result| result.map(|(_, p_value)| p_value))
+        .collect();
+    let validity: BooleanChunked = results
+        .iter()
+        .map(|result| Some(result.is_some()))
+        .collect();
+
+    let fields = [
+        z_scores
+            .into_series()
+            .with_name(PlSmallStr::from("z_score")),
+        p_values
+            .into_series()
+            .with_name(PlSmallStr::from("p_value")),
+    ];
+    let structs = StructChunked::from_series(PlSmallStr::from(""), results.len(), fields.iter())?
+        .with_outer_validity_chunked(validity);
+    Ok(structs.into_series())
+}
+
+/// `Struct{z_score, p_value}`.
+pub fn getis_ord_gi_star_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new(PlSmallStr::from("z_score"), DataType::Float64),
+        Field::new(PlSmallStr::from("p_value"), DataType::Float64),
+    ])
+}
+
 pub fn grid_path_cells(
     origin_series: &Series,
     destination_series: &Series,
@@ -210,6 +802,340 @@ pub fn grid_path_cells(
     cast_list_u64_to_dtype(&paths_series, &DataType::UInt64, Some(&target_inner_dtype))
 }
 
+/// An A* frontier entry, ordered so that `BinaryHeap` pops the lowest
+/// estimated total cost first.
+struct Frontier {
+    estimate: f64,
+    cost: f64,
+    cell: CellIndex,
+}
+
+impl PartialEq for Frontier {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Frontier {}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .total_cmp(&self.estimate)
+            .then_with(|| u64::from(other.cell).cmp(&u64::from(self.cell)))
+    }
+}
+
+/// Cheapest path from `origin` to `destination` over hexagon adjacency that
+/// avoids `blocked`, where entering a cell costs its entry in `costs` or 1.
+/// The search gives up after expanding `max_cells` cells.
+fn path_avoiding(
+    origin: CellIndex,
+    destination: CellIndex,
+    blocked: &HashSet<CellIndex>,
+    costs: &HashMap<CellIndex, f64>,
+    max_cells: usize,
+) -> Option<(Vec<u64>, f64)> {
+    if origin.resolution() != destination.resolution()
+        || blocked.contains(&origin)
+        || blocked.contains(&destination)
+    {
+        return None;
+    }
+
+    // Scaling the grid distance by the cheapest step keeps the heuristic
+    // admissible. It is not consistent where the grid distance can't be
+    // computed (falling back to 0), so cells may be expanded more than once
+    // and the search only stops once the destination is popped.
+    let min_cost = costs.values().copied().fold(1.0, f64::min);
+    let heuristic = |cell: CellIndex| {
+        cell.grid_distance(destination)
+            .map_or(0.0, |d| f64::from(d) * min_cost)
+    };
+
+    let mut best: HashMap<CellIndex, f64> = HashMap::from([(origin, 0.0)]);
+    let mut previous: HashMap<CellIndex, CellIndex> = HashMap::new();
+    let mut frontier = BinaryHeap::from([Frontier {
+        estimate: heuristic(origin),
+        cost: 0.0,
+        cell: origin,
+    }]);
+    let mut expansions = 0;
+
+    while let Some(Frontier { cost, cell, .. }) = frontier.pop() {
+        if cell == destination {
+            let mut path = vec![u64::from(cell)];
+            let mut current = cell;
+            while let Some(&prev) = previous.get(&current) {
+                path.push(u64::from(prev));
+                current = prev;
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        // Skip entries superseded by a cheaper route to the same cell
+        if cost > best[&cell] {
+            continue;
+        }
+        expansions += 1;
+        if expansions > max_cells {
+            return None;
+        }
+
+        for neighbor in cell.grid_disk::<Vec<_>>(1) {
+            if neighbor == cell || blocked.contains(&neighbor) {
+                continue;
+            }
+            let step = costs.get(&neighbor).copied().unwrap_or(1.0);
+            if !step.is_finite() {
+                continue;
+            }
+            let next = cost + step;
+            if best.get(&neighbor).is_none_or(|&known| next < known) {
+                best.insert(neighbor, next);
+                previous.insert(neighbor, cell);
+                frontier.push(Frontier {
+                    estimate: next + heuristic(neighbor),
+                    cost: next,
+                    cell: neighbor,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Parse a `List` column of per-row inputs, with null rows as empty inputs. A
+/// single row applies to every path, so it is parsed once and shared through
+/// [`broadcast_row`].
+fn parse_rows<T, F>(series: &Series, len: usize, name: &str, parse: F) -> PolarsResult<Vec<T>>
+where
+    T: Default,
+    F: Fn(&Series) -> PolarsResult<T>,
+{
+    let rows = series.list().map_err(
+        |_| polars_err!(ComputeError: "{} must be a List column, got {}", name, series.dtype()),
+    )?;
+    polars_ensure!(
+        rows.len() == 1 || rows.len() == len,
+        ComputeError: "Length of {} ({}) must match origin ({})",
+        name,
+        rows.len(),
+        len
+    );
+    rows.into_iter()
+        .map(|row| row.map_or_else(|| Ok(T::default()), |row| parse(&row)))
+        .collect()
+}
+
+/// The input for row `i` of rows parsed by [`parse_rows`].
+fn broadcast_row<T>(rows: &[T], i: usize) -> &T {
+    if rows.len() == 1 {
+        &rows[0]
+    } else {
+        &rows[i]
+    }
+}
+
+/// Parse a row of `Struct{cell, cost}` traversal costs.
+fn parse_costs(row: &Series) -> PolarsResult<HashMap<CellIndex, f64>> {
+    let fields = row.struct_()?.fields_as_series();
+    polars_ensure!(
+        fields.len() == 2,
+        ComputeError: "costs must be a List(Struct{{cell, cost}}) column"
+    );
+    let cells = parse_cell_indices(&fields[0])?;
+    let costs = fields[1].cast(&DataType::Float64)?;
+
+    let mut parsed = HashMap::new();
+    for (cell, cost) in cells.into_iter().zip(costs.f64()?) {
+        if let (Some(cell), Some(cost)) = (cell, cost) {
+            polars_ensure!(
+                cost >= 0.0,
+                ComputeError: "Traversal costs must be non-negative, got {}", cost
+            );
+            parsed.insert(cell, cost);
+        }
+    }
+    Ok(parsed)
+}
+
+pub fn grid_path_avoiding(inputs: &[Series], max_cells: usize) -> PolarsResult<Series> {
+    let origin_series = &inputs[0];
+    let target_inner_dtype = resolve_target_inner_dtype(origin_series.dtype())?;
+    let origins = parse_cell_indices(origin_series)?;
+    let destinations = parse_cell_indices(&inputs[1])?;
+    polars_ensure!(
+        origins.len() == destinations.len(),
+        ComputeError: "Length of destination ({}) must match origin ({})",
+        destinations.len(),
+        origins.len()
+    );
+    let len = origins.len();
+
+    let blocked: Vec<HashSet<CellIndex>> = parse_rows(&inputs[2], len, "blocked_cells", |row| {
+        Ok(parse_cell_indices(row)?.into_iter().flatten().collect())
+    })?;
+    let costs: Vec<HashMap<CellIndex, f64>> = match inputs.get(3) {
+        Some(cost_series) => parse_rows(cost_series, len, "costs", parse_costs)?,
+        None => vec![HashMap::new()],
+    };
+
+    let results: Vec<Option<(Vec<u64>, f64)>> = (0..len)
+        .into_par_iter()
+        .map(|i| {
+            path_avoiding(
+                origins[i]?,
+                destinations[i]?,
+                broadcast_row(&blocked, i),
+                broadcast_row(&costs, i),
+                max_cells,
+            )
+        })
+        .collect();
+
+    let paths: ListChunked = results
+        .iter()
+        .map(|result| {
+            result
+                .as_ref()
+                .map(|(path, _)| Series::new(PlSmallStr::from(""), path.as_slice()))
+        })
+        .collect();
+    let paths = cast_list_u64_to_dtype(
+        &paths.into_series(),
+        &DataType::UInt64,
+        Some(&target_inner_dtype),
+    )?
+    .cast(&DataType::List(Box::new(target_inner_dtype)))?
+    .with_name(PlSmallStr::from("path"));
+    let total_costs: Float64Chunked = results
+        .iter()
+        .map(|result| result.as_ref().map(|&(_, cost)| cost))
+        .collect();
+    let validity: BooleanChunked = results
+        .iter()
+        .map(|result| Some(result.is_some()))
+        .collect();
+
+    let fields = [
+        paths,
+        total_costs
+            .into_series()
+            .with_name(PlSmallStr::from("cost")),
+    ];
+    let structs = StructChunked::from_series(PlSmallStr::from(""), len, fields.iter())?
+        .with_outer_validity_chunked(validity);
+    Ok(structs.into_series())
+}
+
+/// `Struct{path, cost}`, with the path in the input's cell dtype.
+pub fn grid_path_avoiding_dtype(cell_dtype: &DataType) -> DataType {
+    DataType::Struct(vec![
+        Field::new(
+            PlSmallStr::from("path"),
+            DataType::List(Box::new(cell_dtype.clone())),
+        ),
+        Field::new(PlSmallStr::from("cost"), DataType::Float64),
+    ])
+}
+
+/// Parse a WKT string into line strings, returning `None` for invalid WKT or
+/// geometries that are not a `LINESTRING`/`MULTILINESTRING`.
+fn parse_wkt_lines(wkt: &str) -> Option<Vec<LineString>> {
+    match Geometry::<f64>::try_from_wkt_str(wkt).ok()? {
+        Geometry::LineString(line) => Some(vec![line]),
+        Geometry::MultiLineString(multi_line) => Some(multi_line.0),
+        _ => None,
+    }
+}
+
+fn line_cells(lines: Vec<LineString>, resolution: Resolution) -> Option<Vec<u64>> {
+    let mut plotter = PlotterBuilder::new(resolution).build();
+    for line in lines {
+        match line.0.as_slice() {
+            [] => {},
+            // A single point still covers the cell it falls in
+            [point] => plotter.add(Line::new(*point, *point)).ok()?,
+            _ => plotter.add_batch(line.lines()).ok()?,
+        }
+    }
+
+    // Consecutive segments share their endpoints, and a line may pass through
+    // the same cell more than once, so only the first visit is kept
+    let mut seen = HashSet::new();
+    plotter
+        .plot()
+        .filter_map(|cell| match cell {
+            Ok(cell) => seen.insert(cell).then(|| Ok(u64::from(cell))),
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .ok()
+}
+
+pub fn linestring_to_cells(
+    line_series: &Series,
+    resolution: u8,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let resolution = Resolution::try_from(resolution)
+        .map_err(|_| polars_err!(ComputeError: "Invalid resolution: {}", resolution))?;
+    let unit = parse_angle_unit(unit)?;
+
+    let lines: Vec<Option<Vec<LineString>>> = match line_series.dtype() {
+        DataType::String => line_series
+            .str()?
+            .into_iter()
+            .map(|wkt| wkt.and_then(parse_wkt_lines))
+            .collect(),
+        DataType::List(_) => line_series
+            .cast(&DataType::List(Box::new(DataType::List(Box::new(
+                DataType::Float64,
+            )))))?
+            .list()?
+            .into_iter()
+            .map(|coords| {
+                coords
+                    .and_then(|coords| parse_latlng_coords(&coords, unit))
+                    .map(|line| vec![line])
+            })
+            .collect(),
+        dtype => polars_bail!(
+            ComputeError: "Unsupported line type: {}. Expected WKT strings or List(List(Float64)) coordinates",
+            dtype
+        ),
+    };
+
+    let cells: ListChunked = lines
+        .into_par_iter()
+        .map(|lines| {
+            lines
+                .and_then(|lines| line_cells(lines, resolution))
+                .map(|cells| Series::new(PlSmallStr::from(""), cells.as_slice()))
+        })
+        .collect();
+
+    Ok(cells.into_series())
+}
+
+pub fn linestring_to_cells_string(
+    line_series: &Series,
+    resolution: u8,
+    unit: &str,
+) -> PolarsResult<Series> {
+    let cells = linestring_to_cells(line_series, resolution, unit)?;
+    cast_list_u64_to_dtype(&cells, &DataType::UInt64, Some(&DataType::String))
+}
+
 pub fn cell_to_local_ij(cell_series: &Series, origin_series: &Series) -> PolarsResult<Series> {
     let cells = parse_cell_indices(cell_series)?;
     let origins = parse_cell_indices(origin_series)?;
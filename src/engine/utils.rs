@@ -1,4 +1,5 @@
-use h3o::CellIndex;
+use geo::{Coord, Geometry, LineString, MultiLineString, MultiPolygon, Polygon};
+use h3o::{CellIndex, LatLng, Resolution};
 use polars::error::PolarsResult;
 use polars::prelude::*;
 
@@ -30,6 +31,70 @@ pub fn parse_cell_indices(cell_series: &Series) -> PolarsResult<Vec<Option<CellI
     })
 }
 
+/// Parse a resolution column, with null for missing or out-of-range values.
+fn parse_resolutions(resolution_series: &Series) -> PolarsResult<Vec<Option<Resolution>>> {
+    Ok(resolution_series
+        .cast(&DataType::Int64)?
+        .i64()?
+        .into_iter()
+        .map(|opt| {
+            opt.and_then(|v| u8::try_from(v).ok())
+                .and_then(|v| Resolution::try_from(v).ok())
+        })
+        .collect())
+}
+
+/// Resolve a resolution argument for `len` rows. A single value is broadcast
+/// to every row and a column is matched row by row, with null for missing or
+/// invalid resolutions either way. A one-row column can't be told apart from a
+/// literal here, so literals are validated on the Python side instead.
+pub fn broadcast_resolutions(
+    resolution_series: &Series,
+    len: usize,
+) -> PolarsResult<Vec<Option<Resolution>>> {
+    let resolutions = parse_resolutions(resolution_series)?;
+
+    if resolutions.len() == 1 {
+        return Ok(vec![resolutions[0]; len]);
+    }
+
+    polars_ensure!(
+        resolutions.len() == len,
+        ComputeError: "Length of resolution ({}) must match input ({})",
+        resolutions.len(),
+        len
+    );
+    Ok(resolutions)
+}
+
+/// Resolve a numeric argument such as a coordinate or radius for `len` rows,
+/// broadcasting a single value the same way as [`broadcast_resolutions`]. A
+/// null stays null, so a one-row column behaves like any other column.
+pub fn broadcast_floats(series: &Series, len: usize, name: &str) -> PolarsResult<Vec<Option<f64>>> {
+    polars_ensure!(
+        series.dtype().is_numeric(),
+        ComputeError: "{} must be numeric, got {}", name, series.dtype()
+    );
+    let values: Vec<Option<f64>> = series
+        .cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
+        .collect();
+
+    if values.len() == 1 {
+        return Ok(vec![values[0]; len]);
+    }
+
+    polars_ensure!(
+        values.len() == len,
+        ComputeError: "Length of {} ({}) must match input ({})",
+        name,
+        values.len(),
+        len
+    );
+    Ok(values)
+}
+
 pub fn cast_u64_to_dtype(
     original_dtype: &DataType,
     target_dtype: Option<&DataType>,
@@ -141,3 +206,494 @@ pub fn bail_if_null_many(checks: &[(&Series, &str)]) -> PolarsResult<()> {
     }
     Ok(())
 }
+
+// WKB geometry type codes (ISO, 2D).
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+
+fn wkb_header(buf: &mut Vec<u8>, geometry_type: u32) {
+    // Always little-endian
+    buf.push(1);
+    buf.extend_from_slice(&geometry_type.to_le_bytes());
+}
+
+fn wkb_coords(buf: &mut Vec<u8>, coords: &[(f64, f64)]) {
+    buf.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+    for (lng, lat) in coords {
+        buf.extend_from_slice(&lng.to_le_bytes());
+        buf.extend_from_slice(&lat.to_le_bytes());
+    }
+}
+
+fn wkb_write_linestring(buf: &mut Vec<u8>, coords: &[(f64, f64)]) {
+    wkb_header(buf, WKB_LINESTRING);
+    wkb_coords(buf, coords);
+}
+
+fn wkb_write_polygon(buf: &mut Vec<u8>, ring: &[(f64, f64)]) {
+    let mut closed = ring.to_vec();
+    if let (Some(first), Some(last)) = (ring.first(), ring.last()) {
+        if first != last {
+            closed.push(*first);
+        }
+    }
+
+    wkb_header(buf, WKB_POLYGON);
+    buf.extend_from_slice(&1u32.to_le_bytes());
+    wkb_coords(buf, &closed);
+}
+
+/// Encode a coordinate as a WKB `Point` (x = lng, y = lat).
+pub fn wkb_point(coord: LatLng) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(21);
+    wkb_header(&mut buf, WKB_POINT);
+    buf.extend_from_slice(&coord.lng().to_le_bytes());
+    buf.extend_from_slice(&coord.lat().to_le_bytes());
+    buf
+}
+
+/// Encode `(lng, lat)` lines as a WKB `LineString`, or a `MultiLineString`
+/// when there is more than one part.
+pub fn wkb_lines(parts: &[Vec<(f64, f64)>]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    if let [line] = parts {
+        wkb_write_linestring(&mut buf, line);
+    } else {
+        wkb_header(&mut buf, WKB_MULTILINESTRING);
+        buf.extend_from_slice(&(parts.len() as u32).to_le_bytes());
+        for line in parts {
+            wkb_write_linestring(&mut buf, line);
+        }
+    }
+    buf
+}
+
+/// Encode `(lng, lat)` rings as single-ring WKB `Polygon`s, closing them if
+/// needed. More than one ring gives a `MultiPolygon`.
+pub fn wkb_polygons(rings: &[Vec<(f64, f64)>]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    if let [ring] = rings {
+        wkb_write_polygon(&mut buf, ring);
+    } else {
+        wkb_header(&mut buf, WKB_MULTIPOLYGON);
+        buf.extend_from_slice(&(rings.len() as u32).to_le_bytes());
+        for ring in rings {
+            wkb_write_polygon(&mut buf, ring);
+        }
+    }
+    buf
+}
+
+/// Build a `LineString`, or a `MultiLineString` for several parts, from
+/// `(lng, lat)` lines.
+pub fn line_geometry(parts: Vec<Vec<(f64, f64)>>) -> Geometry {
+    let mut lines: Vec<LineString> = parts.into_iter().map(LineString::from).collect();
+    if lines.len() == 1 {
+        Geometry::LineString(lines.remove(0))
+    } else {
+        Geometry::MultiLineString(MultiLineString::new(lines))
+    }
+}
+
+/// Build a `Polygon`, or a `MultiPolygon` for several rings, from `(lng, lat)`
+/// exterior rings.
+pub fn polygon_geometry(rings: Vec<Vec<(f64, f64)>>) -> Geometry {
+    let mut polygons: Vec<Polygon> = rings
+        .into_iter()
+        .map(|ring| Polygon::new(LineString::from(ring), Vec::new()))
+        .collect();
+    if polygons.len() == 1 {
+        Geometry::Polygon(polygons.remove(0))
+    } else {
+        Geometry::MultiPolygon(MultiPolygon::new(polygons))
+    }
+}
+
+/// How geometries crossing the antimeridian are returned.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Antimeridian {
+    /// Keep longitudes in `[-180, 180]`, even if edges jump across the globe.
+    Ignore,
+    /// Split the geometry at ±180° into several parts.
+    Split,
+    /// Shift longitudes past ±180° so the geometry stays continuous.
+    Unwrap,
+}
+
+pub fn parse_antimeridian(mode: &str) -> PolarsResult<Antimeridian> {
+    match mode {
+        "ignore" => Ok(Antimeridian::Ignore),
+        "split" => Ok(Antimeridian::Split),
+        "unwrap" => Ok(Antimeridian::Unwrap),
+        _ => polars_bail!(
+            ComputeError: "Invalid antimeridian mode: {}. Expected 'ignore', 'split' or 'unwrap'",
+            mode
+        ),
+    }
+}
+
+/// Angular unit of coordinates read from or written to columns. H3 itself
+/// works in degrees, WKT/WKB/GeoJSON outputs are always in degrees.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AngleUnit {
+    Degrees,
+    Radians,
+}
+
+impl AngleUnit {
+    /// Convert an angle in degrees into this unit.
+    pub fn from_degrees(self, degrees: f64) -> f64 {
+        match self {
+            Self::Degrees => degrees,
+            Self::Radians => degrees.to_radians(),
+        }
+    }
+
+    /// Convert an angle in this unit into degrees.
+    pub fn to_degrees(self, angle: f64) -> f64 {
+        match self {
+            Self::Degrees => angle,
+            Self::Radians => angle.to_degrees(),
+        }
+    }
+
+    /// Build a coordinate from a latitude and longitude given in this unit.
+    pub fn latlng(self, lat: f64, lng: f64) -> Option<LatLng> {
+        match self {
+            Self::Degrees => LatLng::new(lat, lng).ok(),
+            Self::Radians => LatLng::from_radians(lat, lng).ok(),
+        }
+    }
+}
+
+pub fn parse_angle_unit(unit: &str) -> PolarsResult<AngleUnit> {
+    match unit {
+        "deg" => Ok(AngleUnit::Degrees),
+        "rad" => Ok(AngleUnit::Radians),
+        _ => polars_bail!(ComputeError: "Invalid unit: {}. Expected 'deg' or 'rad'", unit),
+    }
+}
+
+fn convert_parts(parts: Vec<Vec<(f64, f64)>>, unit: AngleUnit) -> Vec<Vec<(f64, f64)>> {
+    if unit == AngleUnit::Degrees {
+        return parts;
+    }
+    parts
+        .into_iter()
+        .map(|part| {
+            part.into_iter()
+                .map(|(lng, lat)| (unit.from_degrees(lng), unit.from_degrees(lat)))
+                .collect()
+        })
+        .collect()
+}
+
+fn lng_lat_pairs(coords: &[LatLng]) -> Vec<(f64, f64)> {
+    coords.iter().map(|v| (v.lng(), v.lat())).collect()
+}
+
+/// Apply an antimeridian mode to a cell boundary, returning one or more open
+/// rings of `(lng, lat)` pairs in `unit`. Only `Split` can return several rings.
+pub fn boundary_rings(
+    ring: &[LatLng],
+    mode: Antimeridian,
+    unit: AngleUnit,
+) -> Vec<Vec<(f64, f64)>> {
+    let rings = match mode {
+        Antimeridian::Ignore => vec![lng_lat_pairs(ring)],
+        Antimeridian::Split => split_antimeridian(ring),
+        Antimeridian::Unwrap => vec![unwrap_longitudes(ring)],
+    };
+    convert_parts(rings, unit)
+}
+
+/// Apply an antimeridian mode to a line such as a directed edge, returning
+/// one or more parts of `(lng, lat)` pairs in `unit`. Only `Split` can return
+/// several.
+pub fn boundary_lines(
+    line: &[LatLng],
+    mode: Antimeridian,
+    unit: AngleUnit,
+) -> Vec<Vec<(f64, f64)>> {
+    let lines = match mode {
+        Antimeridian::Ignore => vec![lng_lat_pairs(line)],
+        Antimeridian::Split => split_line_antimeridian(line),
+        Antimeridian::Unwrap => vec![unwrap_longitudes(line)],
+    };
+    convert_parts(lines, unit)
+}
+
+/// Whether consecutive vertices of a ring jump across the antimeridian.
+pub fn crosses_antimeridian(ring: &[LatLng]) -> bool {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .any(|(a, b)| (a.lng() - b.lng()).abs() > 180.0)
+}
+
+/// Return the ring as `(lng, lat)` pairs with longitudes shifted by 360° where
+/// needed, so that they are continuous (e.g. `179.9` followed by `180.1`).
+pub fn unwrap_longitudes(ring: &[LatLng]) -> Vec<(f64, f64)> {
+    let mut coords: Vec<(f64, f64)> = Vec::with_capacity(ring.len());
+    for vertex in ring {
+        let mut lng = vertex.lng();
+        if let Some(&(prev_lng, _)) = coords.last() {
+            if lng - prev_lng > 180.0 {
+                lng -= 360.0;
+            } else if lng - prev_lng < -180.0 {
+                lng += 360.0;
+            }
+        }
+        coords.push((lng, vertex.lat()));
+    }
+    coords
+}
+
+/// Clip a ring of `(lng, lat)` pairs to one side of the meridian `lng`.
+fn clip_to_meridian(ring: &[(f64, f64)], meridian: f64, keep_west: bool) -> Vec<(f64, f64)> {
+    let inside = |lng: f64| {
+        if keep_west {
+            lng <= meridian
+        } else {
+            lng >= meridian
+        }
+    };
+
+    let mut clipped = Vec::with_capacity(ring.len() + 2);
+    for (i, &curr) in ring.iter().enumerate() {
+        let next = ring[(i + 1) % ring.len()];
+        if inside(curr.0) {
+            clipped.push(curr);
+        }
+        if inside(curr.0) != inside(next.0) {
+            let t = (meridian - curr.0) / (next.0 - curr.0);
+            clipped.push((meridian, curr.1 + t * (next.1 - curr.1)));
+        }
+    }
+    clipped
+}
+
+/// Split a ring crossing the antimeridian into rings that stay within
+/// `[-180, 180]`, returned as open `(lng, lat)` rings.
+///
+/// Rings that don't cross the antimeridian, or that enclose a pole (and
+/// therefore can't be unwrapped into a closed ring), are returned as is.
+pub fn split_antimeridian(ring: &[LatLng]) -> Vec<Vec<(f64, f64)>> {
+    let original = || vec![ring.iter().map(|v| (v.lng(), v.lat())).collect()];
+    if !crosses_antimeridian(ring) {
+        return original();
+    }
+
+    let unwrapped = unwrap_longitudes(ring);
+    let (first, last) = (unwrapped[0], unwrapped[unwrapped.len() - 1]);
+    if (first.0 - last.0).abs() > 180.0 {
+        return original();
+    }
+
+    let (meridian, shift) = if unwrapped.iter().any(|&(lng, _)| lng > 180.0) {
+        (180.0, -360.0)
+    } else {
+        (-180.0, 360.0)
+    };
+    let inner = clip_to_meridian(&unwrapped, meridian, meridian > 0.0);
+    let outer: Vec<(f64, f64)> = clip_to_meridian(&unwrapped, meridian, meridian < 0.0)
+        .into_iter()
+        .map(|(lng, lat)| (lng + shift, lat))
+        .collect();
+
+    [inner, outer]
+        .into_iter()
+        .filter(|part| part.len() >= 3)
+        .collect()
+}
+
+/// Split a line at every antimeridian crossing, ending one part and starting
+/// the next on the meridian itself.
+fn split_line_antimeridian(line: &[LatLng]) -> Vec<Vec<(f64, f64)>> {
+    let mut parts = Vec::new();
+    let mut current: Vec<(f64, f64)> = Vec::with_capacity(line.len());
+    for vertex in line {
+        let (lng, lat) = (vertex.lng(), vertex.lat());
+        if let Some(&(prev_lng, prev_lat)) = current.last() {
+            if (lng - prev_lng).abs() > 180.0 {
+                let meridian = if prev_lng > 0.0 { 180.0 } else { -180.0 };
+                let t = (meridian - prev_lng) / (lng + 2.0 * meridian - prev_lng);
+                let crossing_lat = prev_lat + t * (lat - prev_lat);
+                current.push((meridian, crossing_lat));
+                parts.push(std::mem::take(&mut current));
+                current.push((-meridian, crossing_lat));
+            }
+        }
+        current.push((lng, lat));
+    }
+    parts.push(current);
+    parts
+}
+
+/// Parse a list of `[lat, lng]` pairs in `unit` into a line string in degrees
+/// (`x = lng`, `y = lat`), returning `None` if any pair is null or malformed.
+pub fn parse_latlng_coords(coords: &Series, unit: AngleUnit) -> Option<LineString> {
+    coords
+        .list()
+        .ok()?
+        .into_iter()
+        .map(|pair| {
+            let pair = pair?;
+            let values = pair.f64().ok()?;
+            match (values.len(), values.get(0), values.get(1)) {
+                (2, Some(lat), Some(lng)) => Some(Coord {
+                    x: unit.to_degrees(lng),
+                    y: unit.to_degrees(lat),
+                }),
+                _ => None,
+            }
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(LineString::new)
+}
+
+/// The GeoArrow point layout, `Struct{x: Float64, y: Float64}` with `x = lng`
+/// and `y = lat`.
+pub fn geoarrow_point_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new(PlSmallStr::from("x"), DataType::Float64),
+        Field::new(PlSmallStr::from("y"), DataType::Float64),
+    ])
+}
+
+/// The named struct layout for a single coordinate, `Struct{lat: Float64, lng: Float64}`.
+pub fn latlng_struct_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new(PlSmallStr::from("lat"), DataType::Float64),
+        Field::new(PlSmallStr::from("lng"), DataType::Float64),
+    ])
+}
+
+/// Build a two-field `Float64` struct column with column builders, so no
+/// per-row allocation happens. Null coordinates become null structs.
+fn coordinate_struct(names: [&str; 2], coords: &[Option<(f64, f64)>]) -> PolarsResult<Series> {
+    let mut first = PrimitiveChunkedBuilder::<Float64Type>::new(names[0].into(), coords.len());
+    let mut second = PrimitiveChunkedBuilder::<Float64Type>::new(names[1].into(), coords.len());
+    let mut validity = BooleanChunkedBuilder::new(PlSmallStr::from(""), coords.len());
+    for coord in coords {
+        first.append_option(coord.map(|(a, _)| a));
+        second.append_option(coord.map(|(_, b)| b));
+        validity.append_value(coord.is_some());
+    }
+
+    let fields = [first.finish().into_series(), second.finish().into_series()];
+    let structs = StructChunked::from_series(PlSmallStr::from(""), coords.len(), fields.iter())?
+        .with_outer_validity_chunked(validity.finish());
+    Ok(structs.into_series())
+}
+
+/// Build a `Struct{lat, lng}` column from coordinates.
+pub fn latlng_structs(coords: &[Option<LatLng>], unit: AngleUnit) -> PolarsResult<Series> {
+    let pairs: Vec<Option<(f64, f64)>> = coords
+        .iter()
+        .map(|c| c.map(|ll| (unit.from_degrees(ll.lat()), unit.from_degrees(ll.lng()))))
+        .collect();
+    coordinate_struct(["lat", "lng"], &pairs)
+}
+
+/// Build a GeoArrow point column from coordinates.
+pub fn latlng_geoarrow_points(coords: &[Option<LatLng>], unit: AngleUnit) -> PolarsResult<Series> {
+    let pairs: Vec<Option<(f64, f64)>> = coords
+        .iter()
+        .map(|c| c.map(|ll| (unit.from_degrees(ll.lng()), unit.from_degrees(ll.lat()))))
+        .collect();
+    geoarrow_points(&pairs)
+}
+
+/// Build a `List(Float64)` column of `[lat, lng]` pairs from coordinates.
+pub fn latlng_lists(coords: &[Option<LatLng>], unit: AngleUnit) -> Series {
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        PlSmallStr::from(""),
+        coords.len(),
+        coords.len() * 2,
+        DataType::Float64,
+    );
+    for coord in coords {
+        match coord {
+            Some(ll) => {
+                builder.append_slice(&[unit.from_degrees(ll.lat()), unit.from_degrees(ll.lng())])
+            },
+            None => builder.append_null(),
+        }
+    }
+    builder.finish().into_series()
+}
+
+/// Build a GeoArrow point array from `(lng, lat)` coordinates.
+pub fn geoarrow_points(coords: &[Option<(f64, f64)>]) -> PolarsResult<Series> {
+    coordinate_struct(["x", "y"], coords)
+}
+
+/// Build a GeoArrow polygon (a list of rings of points) from a single
+/// `(lng, lat)` ring, closing it as GeoArrow expects.
+pub fn geoarrow_polygon(ring: &[(f64, f64)]) -> PolarsResult<Series> {
+    let mut coords: Vec<Option<(f64, f64)>> = ring.iter().copied().map(Some).collect();
+    if let Some(&first) = coords.first() {
+        coords.push(first);
+    }
+    let ring = geoarrow_points(&coords)?;
+    Ok(Series::new(PlSmallStr::from(""), [ring]))
+}
+
+/// Build a GeoArrow multipolygon (a list of single-ring polygons) from
+/// `(lng, lat)` rings.
+pub fn geoarrow_multipolygon(rings: &[Vec<(f64, f64)>]) -> PolarsResult<Series> {
+    let polygons = rings
+        .iter()
+        .map(|ring| geoarrow_polygon(ring))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(Series::new(PlSmallStr::from(""), polygons))
+}
+
+/// Build a `List(Float64)` series of `[lat, lng]` pairs from `(lng, lat)`
+/// coordinates.
+pub fn latlng_pair_lists(coords: &[(f64, f64)]) -> Series {
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        PlSmallStr::from(""),
+        coords.len(),
+        coords.len() * 2,
+        DataType::Float64,
+    );
+    for (lng, lat) in coords {
+        builder.append_slice(&[*lat, *lng]);
+    }
+    builder.finish().into_series()
+}
+
+/// Build the `[lat, lng]` list value of a boundary: a single list of pairs,
+/// or a list of such parts when splitting at the antimeridian.
+pub fn boundary_list(parts: &[Vec<(f64, f64)>], mode: Antimeridian) -> Series {
+    if mode == Antimeridian::Split {
+        let parts: Vec<Series> = parts.iter().map(|part| latlng_pair_lists(part)).collect();
+        Series::new(PlSmallStr::from(""), parts)
+    } else {
+        latlng_pair_lists(&parts[0])
+    }
+}
+
+/// Split a GeoArrow point column into its `lat` and `lng` series.
+pub fn parse_geoarrow_points(point_series: &Series) -> PolarsResult<(Series, Series)> {
+    let points = point_series.struct_().map_err(|_| {
+        polars_err!(ComputeError: "Expected a GeoArrow point column (Struct{{x, y}}), got {}", point_series.dtype())
+    })?;
+    // Null points are null in the struct itself, not necessarily in its fields
+    let valid = point_series.is_not_null();
+    let field = |name: &str| -> PolarsResult<Series> {
+        let values = points.field_by_name(name)?.cast(&DataType::Float64)?;
+        Ok(values
+            .f64()?
+            .into_iter()
+            .zip(&valid)
+            .map(|(value, valid)| value.filter(|_| valid == Some(true)))
+            .collect::<Float64Chunked>()
+            .into_series())
+    };
+    Ok((field("y")?, field("x")?))
+}
@@ -2,7 +2,10 @@ use h3o::{LatLng, Vertex, VertexIndex};
 use polars::prelude::*;
 use rayon::prelude::*;
 
-use super::utils::parse_cell_indices;
+use super::utils::{
+    latlng_geoarrow_points, latlng_lists, latlng_structs, parse_angle_unit, parse_cell_indices,
+    wkb_point,
+};
 
 pub fn cell_to_vertex(cell_series: &Series, vertex_num: u8) -> PolarsResult<Series> {
     // Try to create vertex first to validate the number
@@ -36,9 +39,8 @@ pub fn cell_to_vertexes(cell_series: &Series) -> PolarsResult<Series> {
     Ok(vertex_lists.into_series())
 }
 
-pub fn vertex_to_latlng(vertex_series: &Series) -> PolarsResult<Series> {
-    // Parse vertex indices from various input types
-    let vertices: Vec<Option<VertexIndex>> = match vertex_series.dtype() {
+fn parse_vertex_indices(vertex_series: &Series) -> PolarsResult<Vec<Option<VertexIndex>>> {
+    Ok(match vertex_series.dtype() {
         DataType::UInt64 => vertex_series
             .u64()?
             .into_iter()
@@ -62,19 +64,41 @@ pub fn vertex_to_latlng(vertex_series: &Series) -> PolarsResult<Series> {
                 format!("Unsupported type for vertex: {:?}", vertex_series.dtype()).into(),
             ))
         },
-    };
+    })
+}
 
-    let coords: ListChunked = vertices
+fn vertex_coords(vertex_series: &Series) -> PolarsResult<Vec<Option<LatLng>>> {
+    let vertices = parse_vertex_indices(vertex_series)?;
+    Ok(vertices
         .into_par_iter()
-        .map(|vertex| {
-            vertex.map(|idx| {
-                let latlng = LatLng::from(idx);
-                Series::new(PlSmallStr::from(""), &[latlng.lat(), latlng.lng()])
-            })
-        })
+        .map(|vertex| vertex.map(LatLng::from))
+        .collect())
+}
+
+pub fn vertex_to_latlng(vertex_series: &Series, unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
+    Ok(latlng_lists(&vertex_coords(vertex_series)?, unit))
+}
+
+pub fn vertex_to_latlng_struct(vertex_series: &Series, unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
+    latlng_structs(&vertex_coords(vertex_series)?, unit)
+}
+
+pub fn vertex_to_latlng_geoarrow(vertex_series: &Series, unit: &str) -> PolarsResult<Series> {
+    let unit = parse_angle_unit(unit)?;
+    latlng_geoarrow_points(&vertex_coords(vertex_series)?, unit)
+}
+
+pub fn vertex_to_latlng_wkb(vertex_series: &Series) -> PolarsResult<Series> {
+    let vertices = parse_vertex_indices(vertex_series)?;
+
+    let wkbs: BinaryChunked = vertices
+        .into_par_iter()
+        .map(|vertex| vertex.map(|idx| wkb_point(LatLng::from(idx))))
         .collect();
 
-    Ok(coords.into_series())
+    Ok(wkbs.into_series())
 }
 
 pub fn is_valid_vertex(vertex_series: &Series) -> PolarsResult<Series> {
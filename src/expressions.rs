@@ -4,8 +4,65 @@ use pyo3_polars::derive::polars_expr;
 use serde::Deserialize;
 
 #[derive(Deserialize)]
-struct LatLngToCellKwargs {
+struct LineStringToCellsKwargs {
     resolution: u8,
+    unit: String,
+}
+
+#[derive(Deserialize)]
+struct PolygonToCellsKwargs {
+    resolution: u8,
+    containment: String,
+    unit: String,
+}
+
+#[derive(Deserialize)]
+struct CircleToCellsKwargs {
+    mode: String,
+    max_cells: usize,
+    unit: String,
+}
+
+#[derive(Deserialize)]
+struct GridRingKwargs {
+    strict: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ConnectedComponentsKwargs {
+    order: u32,
+}
+
+#[derive(Deserialize)]
+struct MorphologyKwargs {
+    compact: bool,
+}
+
+#[derive(Deserialize)]
+struct KringSmoothKwargs {
+    kernel: String,
+    sigma: f64,
+}
+
+#[derive(Deserialize)]
+struct PathAvoidingKwargs {
+    max_cells: usize,
+}
+
+#[derive(Deserialize)]
+struct AntimeridianKwargs {
+    antimeridian: String,
+}
+
+#[derive(Deserialize)]
+struct BoundaryKwargs {
+    antimeridian: String,
+    unit: String,
+}
+
+#[derive(Deserialize)]
+struct UnitKwargs {
+    unit: String,
 }
 
 #[derive(Deserialize)]
@@ -21,6 +78,29 @@ fn latlng_list_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
     Ok(field)
 }
 
+fn geoarrow_point_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        input_fields[0].name.clone(),
+        crate::engine::utils::geoarrow_point_dtype(),
+    ))
+}
+
+fn latlng_struct_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        input_fields[0].name.clone(),
+        crate::engine::utils::latlng_struct_dtype(),
+    ))
+}
+
+fn geoarrow_polygon_dtype(input_fields: &[Field], kwargs: BoundaryKwargs) -> PolarsResult<Field> {
+    let ring = DataType::List(Box::new(crate::engine::utils::geoarrow_point_dtype()));
+    let mut dtype = DataType::List(Box::new(ring));
+    if kwargs.antimeridian == "split" {
+        dtype = DataType::List(Box::new(dtype));
+    }
+    Ok(Field::new(input_fields[0].name.clone(), dtype))
+}
+
 fn map_list_dtype(dt: &DataType) -> PolarsResult<DataType> {
     match dt {
         DataType::List(inner) => {
@@ -62,45 +142,170 @@ fn dynamic_scalar_output_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
 // ===== Indexing ===== //
 
 #[polars_expr(output_type=UInt64)]
-fn latlng_to_cell(inputs: &[Series], kwargs: LatLngToCellKwargs) -> PolarsResult<Series> {
+fn latlng_to_cell(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
     let lat_series = &inputs[0];
     let lng_series = &inputs[1];
-    let resolution = kwargs.resolution;
+    let resolution_series = &inputs[2];
 
-    crate::engine::indexing::latlng_to_cell(lat_series, lng_series, resolution)
+    crate::engine::indexing::latlng_to_cell(lat_series, lng_series, resolution_series, &kwargs.unit)
 }
 
 #[polars_expr(output_type = String)]
-fn latlng_to_cell_string(inputs: &[Series], kwargs: LatLngToCellKwargs) -> PolarsResult<Series> {
+fn latlng_to_cell_string(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
     let lat_series = &inputs[0];
     let lng_series = &inputs[1];
-    let resolution = kwargs.resolution;
+    let resolution_series = &inputs[2];
+
+    crate::engine::indexing::latlng_to_cell_string(
+        lat_series,
+        lng_series,
+        resolution_series,
+        &kwargs.unit,
+    )
+}
 
-    crate::engine::indexing::latlng_to_cell_string(lat_series, lng_series, resolution)
+#[polars_expr(output_type=UInt64)]
+fn point_to_cell(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    let point_series = &inputs[0];
+    let resolution_series = &inputs[1];
+    crate::engine::indexing::point_to_cell(point_series, resolution_series, &kwargs.unit)
+}
+
+#[polars_expr(output_type=String)]
+fn point_to_cell_string(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    let point_series = &inputs[0];
+    let resolution_series = &inputs[1];
+    crate::engine::indexing::point_to_cell_string(point_series, resolution_series, &kwargs.unit)
 }
 
 #[polars_expr(output_type=Float64)]
-fn cell_to_lat(inputs: &[Series]) -> PolarsResult<Series> {
+fn cell_to_lat(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
-    crate::engine::indexing::cell_to_lat(cell_series)
+    crate::engine::indexing::cell_to_lat(cell_series, &kwargs.unit)
 }
 
 #[polars_expr(output_type=Float64)]
-fn cell_to_lng(inputs: &[Series]) -> PolarsResult<Series> {
+fn cell_to_lng(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
-    crate::engine::indexing::cell_to_lng(cell_series)
+    crate::engine::indexing::cell_to_lng(cell_series, &kwargs.unit)
 }
 
 #[polars_expr(output_type_func=latlng_list_dtype)]
-fn cell_to_latlng(inputs: &[Series]) -> PolarsResult<Series> {
+fn cell_to_latlng(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    let cell_series = &inputs[0];
+    crate::engine::indexing::cell_to_latlng(cell_series, &kwargs.unit)
+}
+
+#[polars_expr(output_type_func=latlng_struct_dtype)]
+fn cell_to_latlng_struct(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    let cell_series = &inputs[0];
+    crate::engine::indexing::cell_to_latlng_struct(cell_series, &kwargs.unit)
+}
+
+#[polars_expr(output_type_func=geoarrow_point_dtype)]
+fn cell_to_latlng_geoarrow(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    let cell_series = &inputs[0];
+    crate::engine::indexing::cell_to_latlng_geoarrow(cell_series, &kwargs.unit)
+}
+
+#[polars_expr(output_type_func_with_kwargs=boundary_list_dtype)]
+fn cell_to_boundary(inputs: &[Series], kwargs: BoundaryKwargs) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
-    crate::engine::indexing::cell_to_latlng(cell_series)
+    crate::engine::indexing::cell_to_boundary(cell_series, &kwargs.antimeridian, &kwargs.unit)
 }
 
-#[polars_expr(output_type_func=boundary_list_dtype)]
-fn cell_to_boundary(inputs: &[Series]) -> PolarsResult<Series> {
+#[polars_expr(output_type_func_with_kwargs=geoarrow_polygon_dtype)]
+fn cell_to_boundary_geoarrow(inputs: &[Series], kwargs: BoundaryKwargs) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
-    crate::engine::indexing::cell_to_boundary(cell_series)
+    crate::engine::indexing::cell_to_boundary_geoarrow(
+        cell_series,
+        &kwargs.antimeridian,
+        &kwargs.unit,
+    )
+}
+
+#[polars_expr(output_type=Binary)]
+fn cell_to_boundary_wkb(inputs: &[Series], kwargs: AntimeridianKwargs) -> PolarsResult<Series> {
+    let cell_series = &inputs[0];
+    crate::engine::indexing::cell_to_boundary_wkb(cell_series, &kwargs.antimeridian)
+}
+
+#[polars_expr(output_type=String)]
+fn cell_to_boundary_wkt(inputs: &[Series], kwargs: AntimeridianKwargs) -> PolarsResult<Series> {
+    let cell_series = &inputs[0];
+    crate::engine::indexing::cell_to_boundary_wkt(cell_series, &kwargs.antimeridian)
+}
+
+#[polars_expr(output_type_func=list_uint64_dtype)]
+fn polygon_to_cells(inputs: &[Series], kwargs: PolygonToCellsKwargs) -> PolarsResult<Series> {
+    let polygon_series = &inputs[0];
+    crate::engine::indexing::polygon_to_cells(
+        polygon_series,
+        kwargs.resolution,
+        &kwargs.containment,
+        &kwargs.unit,
+    )
+}
+
+#[polars_expr(output_type_func=list_string_dtype)]
+fn polygon_to_cells_string(
+    inputs: &[Series],
+    kwargs: PolygonToCellsKwargs,
+) -> PolarsResult<Series> {
+    let polygon_series = &inputs[0];
+    crate::engine::indexing::polygon_to_cells_string(
+        polygon_series,
+        kwargs.resolution,
+        &kwargs.containment,
+        &kwargs.unit,
+    )
+}
+
+#[polars_expr(output_type_func=list_uint64_dtype)]
+fn bbox_to_cells(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    crate::engine::indexing::bbox_to_cells(inputs, &kwargs.unit)
+}
+
+#[polars_expr(output_type_func=list_string_dtype)]
+fn bbox_to_cells_string(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    crate::engine::indexing::bbox_to_cells_string(inputs, &kwargs.unit)
+}
+
+#[polars_expr(output_type_func=list_uint64_dtype)]
+fn circle_to_cells(inputs: &[Series], kwargs: CircleToCellsKwargs) -> PolarsResult<Series> {
+    crate::engine::indexing::circle_to_cells(inputs, &kwargs.mode, kwargs.max_cells, &kwargs.unit)
+}
+
+#[polars_expr(output_type_func=list_string_dtype)]
+fn circle_to_cells_string(inputs: &[Series], kwargs: CircleToCellsKwargs) -> PolarsResult<Series> {
+    crate::engine::indexing::circle_to_cells_string(
+        inputs,
+        &kwargs.mode,
+        kwargs.max_cells,
+        &kwargs.unit,
+    )
+}
+
+#[polars_expr(output_type=Boolean)]
+fn cell_contains_latlng(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    crate::engine::indexing::cell_contains_latlng(inputs, &kwargs.unit)
+}
+
+#[polars_expr(output_type=String)]
+fn cells_to_multi_polygon_wkt(inputs: &[Series]) -> PolarsResult<Series> {
+    let cell_series = &inputs[0];
+    crate::engine::indexing::cells_to_multi_polygon_wkt(cell_series)
+}
+
+#[polars_expr(output_type=String)]
+fn cells_to_multi_polygon_wkt_agg(inputs: &[Series]) -> PolarsResult<Series> {
+    let cell_series = &inputs[0];
+    crate::engine::indexing::cells_to_multi_polygon_wkt_agg(cell_series)
+}
+
+#[polars_expr(output_type=String)]
+fn cells_to_geojson(inputs: &[Series]) -> PolarsResult<Series> {
+    crate::engine::indexing::cells_to_geojson(inputs)
 }
 
 // ===== Inspection ===== //
@@ -163,30 +368,41 @@ fn list_uint64_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
     ))
 }
 
+fn list_string_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        input_fields[0].name.clone(),
+        DataType::List(Box::new(DataType::String)),
+    ))
+}
+
 // ===== Hierarchy ===== //
 
 #[polars_expr(output_type_func=dynamic_scalar_output_dtype)]
-fn cell_to_parent(inputs: &[Series], kwargs: ResolutionKwargs) -> PolarsResult<Series> {
+fn cell_to_parent(inputs: &[Series]) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
-    crate::engine::hierarchy::cell_to_parent(cell_series, kwargs.resolution)
+    let resolution_series = &inputs[1];
+    crate::engine::hierarchy::cell_to_parent(cell_series, resolution_series)
 }
 
 #[polars_expr(output_type_func=dynamic_scalar_output_dtype)]
-fn cell_to_center_child(inputs: &[Series], kwargs: ResolutionKwargs) -> PolarsResult<Series> {
+fn cell_to_center_child(inputs: &[Series]) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
-    crate::engine::hierarchy::cell_to_center_child(cell_series, kwargs.resolution)
+    let resolution_series = &inputs[1];
+    crate::engine::hierarchy::cell_to_center_child(cell_series, resolution_series)
 }
 
 #[polars_expr(output_type=UInt64)]
-fn cell_to_children_size(inputs: &[Series], kwargs: ResolutionKwargs) -> PolarsResult<Series> {
+fn cell_to_children_size(inputs: &[Series]) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
-    crate::engine::hierarchy::cell_to_children_size(cell_series, kwargs.resolution)
+    let resolution_series = &inputs[1];
+    crate::engine::hierarchy::cell_to_children_size(cell_series, resolution_series)
 }
 
 #[polars_expr(output_type_func=dynamic_list_output_dtype)]
-fn cell_to_children(inputs: &[Series], kwargs: ResolutionKwargs) -> PolarsResult<Series> {
+fn cell_to_children(inputs: &[Series]) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
-    crate::engine::hierarchy::cell_to_children(cell_series, kwargs.resolution)
+    let resolution_series = &inputs[1];
+    crate::engine::hierarchy::cell_to_children(cell_series, resolution_series)
 }
 
 #[polars_expr(output_type=UInt64)]
@@ -213,12 +429,10 @@ fn compact_cells(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type_func=list_uint64_dtype)]
-fn uncompact_cells(inputs: &[Series], kwargs: ResolutionKwargs) -> PolarsResult<Series> {
+fn uncompact_cells(inputs: &[Series]) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
-    let resolution = kwargs.resolution.ok_or_else(|| {
-        PolarsError::ComputeError("Resolution required for uncompact_cells".into())
-    })?;
-    crate::engine::hierarchy::uncompact_cells(cell_series, resolution)
+    let resolution_series = &inputs[1];
+    crate::engine::hierarchy::uncompact_cells(cell_series, resolution_series)
 }
 
 // ===== Traversal ===== //
@@ -231,7 +445,7 @@ fn grid_distance(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type_func = dynamic_list_output_dtype)]
-fn grid_ring(inputs: &[Series]) -> PolarsResult<Series> {
+fn grid_ring(inputs: &[Series], kwargs: GridRingKwargs) -> PolarsResult<Series> {
     if inputs.len() != 2 {
         polars_bail!(
             ComputeError:
@@ -239,7 +453,7 @@ fn grid_ring(inputs: &[Series]) -> PolarsResult<Series> {
             inputs.len()
         );
     }
-    crate::engine::traversal::grid_ring(inputs)
+    crate::engine::traversal::grid_ring(inputs, kwargs.strict.as_deref())
 }
 
 #[polars_expr(output_type_func=dynamic_list_output_dtype)]
@@ -254,6 +468,103 @@ fn grid_disk(inputs: &[Series]) -> PolarsResult<Series> {
     crate::engine::traversal::grid_disk(inputs)
 }
 
+fn grid_disk_distances_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    let cell_dtype = map_list_dtype(&input_fields[0].dtype)?;
+    Ok(Field::new(
+        input_fields[0].name.clone(),
+        crate::engine::traversal::grid_disk_distances_dtype(&cell_dtype),
+    ))
+}
+
+#[polars_expr(output_type_func=grid_disk_distances_dtype)]
+fn grid_disk_distances(inputs: &[Series]) -> PolarsResult<Series> {
+    crate::engine::traversal::grid_disk_distances(inputs)
+}
+
+fn list_of_lists_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    let cell_dtype = map_list_dtype(&input_fields[0].dtype)?;
+    Ok(Field::new(
+        input_fields[0].name.clone(),
+        DataType::List(Box::new(DataType::List(Box::new(cell_dtype)))),
+    ))
+}
+
+#[polars_expr(output_type_func=list_of_lists_dtype)]
+fn grid_disk_distances_rings(inputs: &[Series]) -> PolarsResult<Series> {
+    crate::engine::traversal::grid_disk_distances_rings(inputs)
+}
+
+fn distance_matrix_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        input_fields[0].name.clone(),
+        DataType::List(Box::new(DataType::List(Box::new(DataType::Int32)))),
+    ))
+}
+
+#[polars_expr(output_type_func=distance_matrix_dtype)]
+fn grid_distance_matrix(inputs: &[Series]) -> PolarsResult<Series> {
+    crate::engine::traversal::grid_distance_matrix(&inputs[0])
+}
+
+fn distance_pairs_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    let cell_dtype = match map_list_dtype(&input_fields[0].dtype)? {
+        DataType::List(inner) => *inner,
+        dtype => dtype,
+    };
+    Ok(Field::new(
+        input_fields[0].name.clone(),
+        crate::engine::traversal::grid_distance_pairs_dtype(&cell_dtype),
+    ))
+}
+
+#[polars_expr(output_type_func=distance_pairs_dtype)]
+fn grid_distance_matrix_long(inputs: &[Series]) -> PolarsResult<Series> {
+    crate::engine::traversal::grid_distance_matrix_long(&inputs[0])
+}
+
+fn components_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    let dtype = match input_fields[0].dtype {
+        DataType::List(_) => DataType::List(Box::new(DataType::UInt32)),
+        _ => DataType::UInt32,
+    };
+    Ok(Field::new(input_fields[0].name.clone(), dtype))
+}
+
+#[polars_expr(output_type_func=components_dtype)]
+fn cells_connected_components(
+    inputs: &[Series],
+    kwargs: ConnectedComponentsKwargs,
+) -> PolarsResult<Series> {
+    crate::engine::traversal::cells_connected_components(&inputs[0], kwargs.order)
+}
+
+#[polars_expr(output_type_func=dynamic_list_output_dtype)]
+fn cells_dilate(inputs: &[Series], kwargs: MorphologyKwargs) -> PolarsResult<Series> {
+    crate::engine::traversal::cells_dilate(inputs, kwargs.compact)
+}
+
+#[polars_expr(output_type_func=dynamic_list_output_dtype)]
+fn cells_erode(inputs: &[Series], kwargs: MorphologyKwargs) -> PolarsResult<Series> {
+    crate::engine::traversal::cells_erode(inputs, kwargs.compact)
+}
+
+#[polars_expr(output_type=Float64)]
+fn kring_smooth(inputs: &[Series], kwargs: KringSmoothKwargs) -> PolarsResult<Series> {
+    crate::engine::traversal::kring_smooth(inputs, &kwargs.kernel, kwargs.sigma)
+}
+
+fn gi_star_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        input_fields[0].name.clone(),
+        crate::engine::traversal::getis_ord_gi_star_dtype(),
+    ))
+}
+
+#[polars_expr(output_type_func=gi_star_dtype)]
+fn getis_ord_gi_star(inputs: &[Series]) -> PolarsResult<Series> {
+    crate::engine::traversal::getis_ord_gi_star(inputs)
+}
+
 #[polars_expr(output_type_func=dynamic_list_output_dtype)]
 fn grid_path_cells(inputs: &[Series]) -> PolarsResult<Series> {
     let origin_series = &inputs[0];
@@ -261,6 +572,38 @@ fn grid_path_cells(inputs: &[Series]) -> PolarsResult<Series> {
     crate::engine::traversal::grid_path_cells(origin_series, destination_series)
 }
 
+fn path_avoiding_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
+    let cell_dtype = map_list_dtype(&input_fields[0].dtype)?;
+    Ok(Field::new(
+        input_fields[0].name.clone(),
+        crate::engine::traversal::grid_path_avoiding_dtype(&cell_dtype),
+    ))
+}
+
+#[polars_expr(output_type_func=path_avoiding_dtype)]
+fn grid_path_avoiding(inputs: &[Series], kwargs: PathAvoidingKwargs) -> PolarsResult<Series> {
+    crate::engine::traversal::grid_path_avoiding(inputs, kwargs.max_cells)
+}
+
+#[polars_expr(output_type_func=list_uint64_dtype)]
+fn linestring_to_cells(inputs: &[Series], kwargs: LineStringToCellsKwargs) -> PolarsResult<Series> {
+    let line_series = &inputs[0];
+    crate::engine::traversal::linestring_to_cells(line_series, kwargs.resolution, &kwargs.unit)
+}
+
+#[polars_expr(output_type_func=list_string_dtype)]
+fn linestring_to_cells_string(
+    inputs: &[Series],
+    kwargs: LineStringToCellsKwargs,
+) -> PolarsResult<Series> {
+    let line_series = &inputs[0];
+    crate::engine::traversal::linestring_to_cells_string(
+        line_series,
+        kwargs.resolution,
+        &kwargs.unit,
+    )
+}
+
 fn ij_list_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
     Ok(Field::new(
         input_fields[0].name.clone(),
@@ -303,9 +646,27 @@ fn cell_to_vertexes(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type_func=latlng_list_dtype)]
-fn vertex_to_latlng(inputs: &[Series]) -> PolarsResult<Series> {
+fn vertex_to_latlng(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    let vertex_series = &inputs[0];
+    crate::engine::vertexes::vertex_to_latlng(vertex_series, &kwargs.unit)
+}
+
+#[polars_expr(output_type_func=latlng_struct_dtype)]
+fn vertex_to_latlng_struct(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
+    let vertex_series = &inputs[0];
+    crate::engine::vertexes::vertex_to_latlng_struct(vertex_series, &kwargs.unit)
+}
+
+#[polars_expr(output_type_func=geoarrow_point_dtype)]
+fn vertex_to_latlng_geoarrow(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
     let vertex_series = &inputs[0];
-    crate::engine::vertexes::vertex_to_latlng(vertex_series)
+    crate::engine::vertexes::vertex_to_latlng_geoarrow(vertex_series, &kwargs.unit)
+}
+
+#[polars_expr(output_type=Binary)]
+fn vertex_to_latlng_wkb(inputs: &[Series]) -> PolarsResult<Series> {
+    let vertex_series = &inputs[0];
+    crate::engine::vertexes::vertex_to_latlng_wkb(vertex_series)
 }
 
 #[polars_expr(output_type=Boolean)]
@@ -316,11 +677,12 @@ fn is_valid_vertex(inputs: &[Series]) -> PolarsResult<Series> {
 
 // ===== Edge ===== //
 
-fn boundary_list_dtype(input_fields: &[Field]) -> PolarsResult<Field> {
-    Ok(Field::new(
-        input_fields[0].name.clone(),
-        DataType::List(Box::new(DataType::List(Box::new(DataType::Float64)))),
-    ))
+fn boundary_list_dtype(input_fields: &[Field], kwargs: BoundaryKwargs) -> PolarsResult<Field> {
+    let mut dtype = DataType::List(Box::new(DataType::List(Box::new(DataType::Float64))));
+    if kwargs.antimeridian == "split" {
+        dtype = DataType::List(Box::new(dtype));
+    }
+    Ok(Field::new(input_fields[0].name.clone(), dtype))
 }
 
 #[polars_expr(output_type=Boolean)]
@@ -367,19 +729,47 @@ fn origin_to_directed_edges(inputs: &[Series]) -> PolarsResult<Series> {
     crate::engine::edge::origin_to_directed_edges(cell_series)
 }
 
-#[polars_expr(output_type_func=boundary_list_dtype)]
-fn directed_edge_to_boundary(inputs: &[Series]) -> PolarsResult<Series> {
+#[polars_expr(output_type_func=dynamic_list_output_dtype)]
+fn cells_boundary(inputs: &[Series]) -> PolarsResult<Series> {
+    crate::engine::edge::cells_boundary(&inputs[0])
+}
+
+#[polars_expr(output_type_func=dynamic_list_output_dtype)]
+fn cells_outer_ring(inputs: &[Series]) -> PolarsResult<Series> {
+    crate::engine::edge::cells_outer_ring(&inputs[0])
+}
+
+#[polars_expr(output_type=UInt32)]
+fn cells_perimeter_edges(inputs: &[Series]) -> PolarsResult<Series> {
+    crate::engine::edge::cells_perimeter_edges(&inputs[0])
+}
+
+#[polars_expr(output_type_func_with_kwargs=boundary_list_dtype)]
+fn directed_edge_to_boundary(inputs: &[Series], kwargs: BoundaryKwargs) -> PolarsResult<Series> {
     let edge_series = &inputs[0];
-    crate::engine::edge::directed_edge_to_boundary(edge_series)
+    crate::engine::edge::directed_edge_to_boundary(edge_series, &kwargs.antimeridian, &kwargs.unit)
 }
 
-// ===== Metrics ===== //
+#[polars_expr(output_type=String)]
+fn directed_edge_to_boundary_wkt(
+    inputs: &[Series],
+    kwargs: AntimeridianKwargs,
+) -> PolarsResult<Series> {
+    let edge_series = &inputs[0];
+    crate::engine::edge::directed_edge_to_boundary_wkt(edge_series, &kwargs.antimeridian)
+}
 
-#[derive(Deserialize)]
-struct UnitKwargs {
-    unit: String,
+#[polars_expr(output_type=Binary)]
+fn directed_edge_to_boundary_wkb(
+    inputs: &[Series],
+    kwargs: AntimeridianKwargs,
+) -> PolarsResult<Series> {
+    let edge_series = &inputs[0];
+    crate::engine::edge::directed_edge_to_boundary_wkb(edge_series, &kwargs.antimeridian)
 }
 
+// ===== Metrics ===== //
+
 #[polars_expr(output_type=Float64)]
 fn cell_area(inputs: &[Series], kwargs: UnitKwargs) -> PolarsResult<Series> {
     let cell_series = &inputs[0];
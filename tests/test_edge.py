@@ -1,3 +1,6 @@
+import math
+import struct
+
 import polars as pl
 import pytest
 
@@ -201,3 +204,128 @@ def test_cells_to_directed_edge(test_params):
         schema=test_params["schema"],
     ).with_columns(edge=plh3.cells_to_directed_edge("origin", "destination"))
     assert df["edge"][0] == test_params["output"]
+
+
+def test_directed_edge_to_boundary_wkt():
+    df = pl.DataFrame(
+        {"edge": [1608492358964346879, None]}, schema={"edge": pl.UInt64}
+    ).with_columns(wkt=plh3.directed_edge_to_boundary_wkt("edge"))
+    wkt = df["wkt"][0]
+    assert wkt.startswith("LINESTRING(")
+    coords = [
+        tuple(map(float, pair.split(" ")))
+        for pair in wkt[len("LINESTRING(") : -len(")")].split(",")
+    ]
+    assert len(coords) == 2
+    lng, lat = coords[0]
+    assert pytest.approx(lng, abs=1e-7) == -121.91508032705622
+    assert pytest.approx(lat, abs=1e-7) == 37.2713558667319
+    assert df["wkt"][1] is None
+
+
+def test_directed_edge_to_boundary_wkb():
+    df = pl.DataFrame(
+        {"edge": [1608492358964346879, None]}, schema={"edge": pl.UInt64}
+    ).with_columns(wkb=plh3.directed_edge_to_boundary_wkb("edge"))
+    assert df["wkb"].dtype == pl.Binary
+    wkb = df["wkb"][0]
+    byte_order, geom_type, num_points = struct.unpack("<BII", wkb[:9])
+    assert (byte_order, geom_type, num_points) == (1, 2, 2)
+    lng, lat = struct.unpack("<dd", wkb[9:25])
+    assert pytest.approx(lng, abs=1e-7) == -121.91508032705622
+    assert pytest.approx(lat, abs=1e-7) == 37.2713558667319
+    assert df["wkb"][1] is None
+
+
+def test_directed_edge_to_boundary():
+    df = pl.DataFrame(
+        {"edge": [1608492358964346879, None]}, schema={"edge": pl.UInt64}
+    ).with_columns(boundary=plh3.directed_edge_to_boundary("edge"))
+    assert df["boundary"].dtype == pl.List(pl.List(pl.Float64))
+    boundary = df["boundary"][0].to_list()
+    # [lat, lng] pairs, not the flat [lat, lng, lat, lng] values of old releases
+    assert len(boundary) == 2
+    assert all(isinstance(pair, list) and len(pair) == 2 for pair in boundary)
+    lat, lng = boundary[0]
+    assert pytest.approx(lat, abs=1e-7) == 37.2713558667319
+    assert pytest.approx(lng, abs=1e-7) == -121.91508032705622
+    assert df["boundary"][1] is None
+
+
+def test_directed_edge_to_boundary_antimeridian():
+    # edge of 825ba7fffffffff running from -178.72 to 179.62 longitude
+    df = pl.DataFrame(
+        {"edge": [1379713919777505279]}, schema={"edge": pl.UInt64}
+    ).with_columns(
+        split=plh3.directed_edge_to_boundary("edge", antimeridian="split"),
+        unwrap=plh3.directed_edge_to_boundary("edge", antimeridian="unwrap"),
+        wkt=plh3.directed_edge_to_boundary_wkt("edge", antimeridian="split"),
+        wkb=plh3.directed_edge_to_boundary_wkb("edge", antimeridian="split"),
+    )
+    parts = df["split"][0].to_list()
+    assert len(parts) == 2
+    assert parts[0][-1][1] == -180
+    assert parts[1][0][1] == 180
+    assert parts[0][-1][0] == parts[1][0][0]
+
+    (_, start), (_, end) = df["unwrap"][0].to_list()
+    assert pytest.approx(start, abs=1e-7) == -178.72069983449614
+    assert pytest.approx(end, abs=1e-7) == -180.3760424455851
+
+    assert df["wkt"][0].startswith("MULTILINESTRING(")
+    assert struct.unpack("<BII", df["wkb"][0][:9]) == (1, 5, 2)
+
+
+def test_directed_edge_to_boundary_radians():
+    df = pl.DataFrame(
+        {"edge": [1608492358964346879]}, schema={"edge": pl.UInt64}
+    ).with_columns(
+        deg=plh3.directed_edge_to_boundary("edge"),
+        rad=plh3.directed_edge_to_boundary("edge", unit="rad"),
+    )
+    for deg, rad in zip(df["deg"][0].to_list(), df["rad"][0].to_list(), strict=True):
+        assert pytest.approx(rad) == [math.radians(v) for v in deg]
+
+
+DISK = [
+    "8928308280fffff",
+    "8928308280bffff",
+    "89283082873ffff",
+    "89283082877ffff",
+    "8928308283bffff",
+    "89283082807ffff",
+    "89283082803ffff",
+]
+
+
+def test_cells_boundary_and_outer_ring():
+    df = pl.DataFrame(
+        {"cells": [DISK, None, ["8928308280fffff", "8928308280fffff"]]},
+        schema={"cells": pl.List(pl.Utf8)},
+    ).with_columns(
+        boundary=plh3.cells_boundary("cells"),
+        outer=plh3.cells_outer_ring("cells"),
+        perimeter=plh3.cells_perimeter_edges("cells"),
+    )
+    # every cell of a k=1 disk but the center touches the outside
+    assert sorted(df["boundary"][0].to_list()) == sorted(DISK[1:])
+    assert df["boundary"].to_list()[1:] == [None, ["8928308280fffff"]]
+    assert df["perimeter"].to_list() == [18, None, 6]
+
+    ring = pl.DataFrame({"cell": ["8928308280fffff"]}).select(
+        plh3.grid_ring("cell", 2)
+    )["cell"][0]
+    assert sorted(df["outer"][0].to_list()) == sorted(ring.to_list())
+    assert len(df["outer"].to_list()[2]) == 6
+
+
+def test_cells_boundary_dtypes():
+    df = pl.DataFrame(
+        {"cells": [[622054503267303423]]}, schema={"cells": pl.List(pl.UInt64)}
+    ).with_columns(
+        boundary=plh3.cells_boundary("cells"),
+        outer=plh3.cells_outer_ring("cells"),
+    )
+    assert df["boundary"].to_list() == [[622054503267303423]]
+    assert df["outer"].dtype == pl.List(pl.UInt64)
+    assert len(df["outer"][0]) == 6
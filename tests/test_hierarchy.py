@@ -160,6 +160,43 @@ def test_invalid_resolutions(resolution: int):
         df.with_columns(children=plh3.cell_to_children("h3_cell", resolution))
 
 
+def test_per_row_resolution():
+    df = pl.DataFrame(
+        {
+            "h3_cell": [586265647244115967] * 5,
+            "parent_res": [0, 1, 2, None, 16],
+            "child_res": [2, 3, 4, None, 16],
+        }
+    ).with_columns(
+        parent=plh3.cell_to_parent("h3_cell", "parent_res"),
+        center_child=plh3.cell_to_center_child("h3_cell", pl.col("child_res")),
+        num_children=plh3.cell_to_children_size("h3_cell", "child_res"),
+    )
+    assert df["parent"].to_list() == [
+        577269992861466623,
+        581764796395814911,
+        586265647244115967,
+        None,
+        None,
+    ]
+    assert df["center_child"].to_list() == [
+        586265647244115967,
+        590768765835149311,
+        595272305332977663,
+        None,
+        None,
+    ]
+    assert df["num_children"].to_list() == [1, 7, 49, None, None]
+
+
+def test_cell_to_children_per_row_resolution():
+    df = pl.DataFrame(
+        {"h3_cell": ["822d57fffffffff", "822d57fffffffff"], "res": [2, 3]}
+    ).with_columns(children=plh3.cell_to_children("h3_cell", "res"))
+    assert df["children"].to_list()[0] == ["822d57fffffffff"]
+    assert len(df["children"].to_list()[1]) == 7
+
+
 def test_compact_cells_valid():
     df = pl.DataFrame(
         {
@@ -209,3 +246,13 @@ def test_uncompact_cells_empty():
         pl.DataFrame({"h3_cells": [[]]}).with_columns(
             uncompacted=plh3.uncompact_cells("h3_cells", 2)
         )
+
+
+def test_uncompact_cells_per_row_resolution():
+    df = pl.DataFrame(
+        {
+            "h3_cells": [[581764796395814911], [581764796395814911]],
+            "res": [1, None],
+        }
+    ).with_columns(uncompacted=plh3.uncompact_cells("h3_cells", "res"))
+    assert df["uncompacted"].to_list() == [[581764796395814911], None]
@@ -1,3 +1,7 @@
+import json
+import math
+import struct
+
 import polars as pl
 import pytest
 
@@ -41,6 +45,54 @@ def test_latlng_to_cell_invalid_resolution(input_lat, input_lng, resolution):
         )
 
 
+def test_latlng_to_cell_per_row_resolution():
+    df = pl.DataFrame(
+        {
+            "lat": [37.7752702151959] * 5,
+            "lng": [-122.418307270836] * 5,
+            "res": [9, 5, 16, None, -1],
+        }
+    ).with_columns(
+        cell_str=plh3.latlng_to_cell("lat", "lng", "res", return_dtype=pl.Utf8),
+        cell_int=plh3.latlng_to_cell("lat", "lng", pl.col("res")),
+        cell_u8=plh3.latlng_to_cell(
+            "lat", "lng", pl.col("res").clip(0, 15).cast(pl.UInt8), pl.Utf8
+        ),
+    )
+    expected = ["8928308280fffff", "85283083fffffff", None, None, None]
+    assert df["cell_str"].to_list() == expected
+    assert df["cell_int"].to_list() == [
+        int(cell, 16) if cell else None for cell in expected
+    ]
+    assert df["cell_u8"].to_list() == [
+        "8928308280fffff",
+        "85283083fffffff",
+        "8f28308280e6c59",
+        None,
+        "8029fffffffffff",
+    ]
+
+
+def test_latlng_to_cell_single_row_resolution_column():
+    # a one-row resolution column is still per-row data, not a literal
+    df = pl.DataFrame(
+        {"lat": [37.7752702151959], "lng": [-122.418307270836], "res": [16]}
+    )
+    cells = df.select(cell=plh3.latlng_to_cell("lat", "lng", "res"))["cell"]
+    assert cells.to_list() == [None]
+    df = pl.DataFrame(
+        {
+            "lat": [37.7752702151959] * 2,
+            "lng": [-122.418307270836] * 2,
+            "res": [9, None],
+            "group": ["a", "b"],
+        }
+    ).with_columns(
+        cell=plh3.latlng_to_cell("lat", "lng", "res", pl.Utf8).over("group")
+    )
+    assert df["cell"].to_list() == ["8928308280fffff", None]
+
+
 def test_latlng_to_cell_missing_lat_lng():
     df = pl.DataFrame({"lat": [None], "lng": [None]})
     with pytest.raises(pl.exceptions.ComputeError):
@@ -169,3 +221,729 @@ def test_cell_to_boundary_known(test_params):
         lat, lng = boundary[i]
         assert pytest.approx(lat, abs=1e-7) == exp_lat
         assert pytest.approx(lng, abs=1e-7) == exp_lng
+
+
+SF_POLYGON = "POLYGON((-122.4089 37.813318, -122.3544 37.7198, -122.5149 37.7081, -122.5283 37.7947, -122.4089 37.813318))"
+SQUARE = "POLYGON((0 0, 1 0, 1 1, 0 1, 0 0))"
+SQUARE_WITH_HOLE = "POLYGON((0 0, 1 0, 1 1, 0 1, 0 0), (0.25 0.25, 0.75 0.25, 0.75 0.75, 0.25 0.75, 0.25 0.25))"
+
+
+@pytest.mark.parametrize(
+    "containment,expected_count",
+    [
+        ("centroid", 26),
+        ("contains", 15),
+        ("intersects", 37),
+        ("covers", 37),
+    ],
+)
+def test_polygon_to_cells_containment(containment, expected_count):
+    df = pl.DataFrame({"polygon": [SF_POLYGON]}).with_columns(
+        cells=plh3.polygon_to_cells("polygon", 7, containment=containment)
+    )
+    assert df["cells"].dtype == pl.List(pl.UInt64)
+    assert len(df["cells"][0]) == expected_count
+
+
+def test_polygon_to_cells_return_dtype():
+    df = pl.DataFrame({"polygon": [SF_POLYGON]}).with_columns(
+        cells_int=plh3.polygon_to_cells("polygon", 7),
+        cells_i64=plh3.polygon_to_cells("polygon", 7, return_dtype=pl.Int64),
+        cells_str=plh3.polygon_to_cells("polygon", 7, return_dtype=pl.Utf8),
+    )
+    assert df["cells_i64"].dtype == pl.List(pl.Int64)
+    assert df["cells_str"].dtype == pl.List(pl.Utf8)
+    assert "872830828ffffff" in df["cells_str"][0].to_list()
+    assert sorted(df["cells_int"][0].to_list()) == sorted(
+        df["cells_i64"][0].to_list()
+    )
+
+
+def test_polygon_to_cells_holes_and_multipolygon():
+    df = pl.DataFrame(
+        {
+            "polygon": [
+                SQUARE,
+                SQUARE_WITH_HOLE,
+                "MULTIPOLYGON(((0 0, 1 0, 1 1, 0 1, 0 0)), ((0.5 0.5, 2 0.5, 2 2, 0.5 2, 0.5 0.5)))",
+            ]
+        }
+    ).with_columns(cells=plh3.polygon_to_cells("polygon", 7))
+    counts = df["cells"].list.len().to_list()
+    assert counts == [3197, 2397, 9523]
+    # overlapping members of a multipolygon don't produce duplicate cells
+    assert df["cells"].list.n_unique().to_list()[2] == 9523
+
+
+def test_polygon_to_cells_coordinate_rings():
+    square = [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]
+    hole = [[0.25, 0.25], [0.25, 0.75], [0.75, 0.75], [0.75, 0.25], [0.25, 0.25]]
+
+    single_ring = pl.DataFrame(
+        {"polygon": [square, None]},
+        schema={"polygon": pl.List(pl.List(pl.Float64))},
+    ).with_columns(cells=plh3.polygon_to_cells("polygon", 7))
+    assert single_ring["cells"].list.len().to_list() == [3197, None]
+
+    with_holes = pl.DataFrame(
+        {"polygon": [[square, hole], [square], [[[0.0, 0.0], [1.0]]]]},
+        schema={"polygon": pl.List(pl.List(pl.List(pl.Float64)))},
+    ).with_columns(cells=plh3.polygon_to_cells("polygon", 7))
+    assert with_holes["cells"].list.len().to_list() == [2397, 3197, None]
+
+
+def test_polygon_to_cells_round_trips_cell_boundary():
+    df = pl.DataFrame({"cell": ["8a1fb46622dffff"]}).with_columns(
+        cells=plh3.polygon_to_cells(
+            plh3.cell_to_boundary("cell"), 10, return_dtype=pl.Utf8
+        )
+    )
+    assert df["cells"].to_list() == [["8a1fb46622dffff"]]
+
+
+def test_polygon_to_cells_radian_rings():
+    square = [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]
+    hole = [[0.25, 0.25], [0.25, 0.75], [0.75, 0.75], [0.75, 0.25], [0.25, 0.25]]
+    rings = [
+        [[math.radians(v) for v in pair] for pair in ring] for ring in (square, hole)
+    ]
+
+    df = pl.DataFrame(
+        {"polygon": [rings]},
+        schema={"polygon": pl.List(pl.List(pl.List(pl.Float64)))},
+    ).with_columns(cells=plh3.polygon_to_cells("polygon", 7, unit="rad"))
+    assert df["cells"].list.len().to_list() == [2397]
+
+    df = pl.DataFrame({"cell": ["8a1fb46622dffff"]}).with_columns(
+        cells=plh3.polygon_to_cells(
+            plh3.cell_to_boundary("cell", unit="rad"),
+            10,
+            return_dtype=pl.Utf8,
+            unit="rad",
+        )
+    )
+    assert df["cells"].to_list() == [["8a1fb46622dffff"]]
+
+
+def test_polygon_to_cells_invalid_inputs():
+    df = pl.DataFrame(
+        {"polygon": ["POINT(1 1)", "not wkt", None]},
+        schema={"polygon": pl.Utf8},
+    ).with_columns(cells=plh3.polygon_to_cells("polygon", 7))
+    assert df["cells"].to_list() == [None, None, None]
+
+
+def test_polygon_to_cells_invalid_params():
+    df = pl.DataFrame({"polygon": [SQUARE]})
+    with pytest.raises(ValueError):
+        df.select(plh3.polygon_to_cells("polygon", 16))
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(
+            plh3.polygon_to_cells("polygon", 7, containment="nearby")  # type: ignore
+        )
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(plh3.polygon_to_cells("polygon", 7, unit="grad"))  # type: ignore[arg-type]
+
+
+def test_bbox_to_cells():
+    df = pl.DataFrame(
+        {"min_lat": [37.7], "min_lng": [-122.5], "max_lat": [37.8], "max_lng": [-122.4]}
+    ).with_columns(
+        cells=plh3.bbox_to_cells(
+            "min_lat", "min_lng", "max_lat", "max_lng", 4, return_dtype=pl.Utf8
+        ),
+        cells_int=plh3.bbox_to_cells("min_lat", "min_lng", "max_lat", "max_lng", 4),
+    )
+    assert df["cells"].to_list() == [["8428309ffffffff"]]
+    assert df["cells_int"].to_list() == [[595182179739238399]]
+
+
+def test_bbox_to_cells_antimeridian():
+    df = pl.select(
+        cells=plh3.bbox_to_cells(-10.0, 179.0, 10.0, -179.0, 2, return_dtype=pl.Utf8)
+    )
+    cells = df["cells"][0].to_list()
+    assert len(cells) == 18
+    assert cells[:3] == ["825b17fffffffff", "825b37fffffffff", "825ba7fffffffff"]
+
+    lngs = pl.DataFrame({"cell": cells}).select(plh3.cell_to_lng("cell"))["cell"]
+    assert (lngs.abs() > 170).all()
+
+
+def test_bbox_to_cells_per_row():
+    df = pl.DataFrame(
+        {
+            "min_lat": [37.7, 40.0, 95.0, None],
+            "max_lat": [37.8, 39.0, 96.0, 1.0],
+            "res": [3, 3, 3, 3],
+        }
+    ).with_columns(
+        cells=plh3.bbox_to_cells("min_lat", -122.5, "max_lat", -122.4, "res")
+    )
+    # inverted and out-of-range latitudes, and null bounds, return None
+    assert df["cells"].to_list() == [[590678605881671679], None, None, None]
+
+
+def test_bbox_to_cells_radians():
+    bounds = [37.7, -122.5, 37.8, -122.4]
+    df = pl.select(
+        deg=plh3.bbox_to_cells(*bounds, 6),
+        rad=plh3.bbox_to_cells(*[math.radians(b) for b in bounds], 6, unit="rad"),
+    )
+    assert len(df["deg"][0]) > 1
+    assert df["deg"].to_list() == df["rad"].to_list()
+
+
+def test_bbox_to_cells_invalid_params():
+    with pytest.raises(ValueError):
+        pl.select(plh3.bbox_to_cells(0.0, 0.0, 1.0, 1.0, 16))
+    with pytest.raises(pl.exceptions.ComputeError):
+        pl.select(plh3.bbox_to_cells(pl.lit("a"), 0.0, 1.0, 1.0, 5))
+    with pytest.raises(pl.exceptions.ComputeError):
+        pl.select(plh3.bbox_to_cells(0.0, 0.0, 1.0, 1.0, 5, unit="grad"))  # type: ignore[arg-type]
+
+
+def test_circle_to_cells():
+    df = pl.DataFrame(
+        {
+            "lat": [37.7752702151959] * 4,
+            "lng": [-122.418307270836] * 4,
+            "radius_m": [0.0, 100.0, 500.0, 1234.0],
+        }
+    ).with_columns(
+        centroid=plh3.circle_to_cells("lat", "lng", "radius_m", 9).list.len(),
+        any=plh3.circle_to_cells("lat", "lng", "radius_m", 9, mode="any").list.len(),
+    )
+    assert df["centroid"].to_list() == [0, 0, 8, 43]
+    assert df["any"].to_list() == [1, 3, 14, 60]
+
+
+def test_circle_to_cells_contents():
+    df = pl.select(
+        cells=plh3.circle_to_cells(
+            37.7752702151959, -122.418307270836, 100.0, 9, "any", pl.Utf8
+        )
+    )
+    assert df["cells"].to_list() == [
+        ["89283082803ffff", "8928308280bffff", "8928308280fffff"]
+    ]
+
+    # the centroid cells all lie within the radius
+    df = pl.select(
+        cell=plh3.circle_to_cells(37.7752702151959, -122.418307270836, 500.0, 9)
+    ).explode("cell")
+    distances = df.select(
+        plh3.great_circle_distance(
+            plh3.cell_to_lat("cell"),
+            plh3.cell_to_lng("cell"),
+            pl.lit(37.7752702151959),
+            pl.lit(-122.418307270836),
+            unit="m",
+        )
+    ).to_series()
+    assert distances.len() == 8
+    assert (distances <= 500).all()
+
+
+def test_circle_to_cells_radians():
+    lat, lng = 37.7752702151959, -122.418307270836
+    df = pl.select(
+        deg=plh3.circle_to_cells(lat, lng, 500.0, 9),
+        rad=plh3.circle_to_cells(
+            math.radians(lat), math.radians(lng), 500.0, 9, unit="rad"
+        ),
+    )
+    assert len(df["deg"][0]) == 8
+    assert df["deg"].to_list() == df["rad"].to_list()
+
+
+def test_circle_to_cells_invalid_rows():
+    df = pl.DataFrame(
+        {"lat": [37.0, 95.0, None, 37.0], "radius_m": [-1.0, 10.0, 10.0, None]},
+        schema={"lat": pl.Float64, "radius_m": pl.Float64},
+    ).with_columns(cells=plh3.circle_to_cells("lat", -122.0, "radius_m", 9))
+    assert df["cells"].to_list() == [None, None, None, None]
+
+    # a one-row column is matched like any other, not rejected as a bad literal
+    df = pl.DataFrame({"radius_m": [None]}, schema={"radius_m": pl.Float64})
+    df = df.with_columns(cells=plh3.circle_to_cells(37.0, -122.0, "radius_m", 9))
+    assert df["cells"].to_list() == [None]
+
+
+def test_circle_to_cells_max_cells():
+    df = pl.DataFrame(
+        {
+            "radius_m": [500.0, 1_000_000.0, 100.0],
+            "res": [9, 9, 15],
+        }
+    ).with_columns(
+        cells=plh3.circle_to_cells(
+            37.7752702151959, -122.418307270836, "radius_m", "res"
+        ).list.len(),
+        capped=plh3.circle_to_cells(
+            37.7752702151959, -122.418307270836, "radius_m", 9, "any", max_cells=5
+        ).list.len(),
+    )
+    # only the rows over the limit are null, the others are still filled
+    assert df["cells"].to_list()[:2] == [8, None]
+    assert df["cells"][2] > 0
+    assert df["capped"].to_list() == [None, None, 3]
+
+
+def test_circle_to_cells_invalid_params():
+    with pytest.raises(ValueError):
+        pl.select(plh3.circle_to_cells(0.0, 0.0, 100.0, 16))
+    with pytest.raises(pl.exceptions.ComputeError):
+        pl.select(plh3.circle_to_cells(0.0, 0.0, 100.0, 9, mode="nearby"))  # type: ignore
+    with pytest.raises(pl.exceptions.ComputeError):
+        pl.select(plh3.circle_to_cells(0.0, 0.0, 100.0, 9, unit="grad"))  # type: ignore[arg-type]
+    with pytest.raises(ValueError):
+        pl.select(plh3.circle_to_cells(0.0, 0.0, 100.0, 9, max_cells=0))
+
+
+def test_cell_contains_latlng():
+    df = pl.DataFrame(
+        {
+            "cell": ["8928308280fffff", "85283083fffffff", "8928308280fffff", None],
+            "lat": [37.7752702151959, 37.7752702151959, 0.0, 37.0],
+            "lng": [-122.418307270836, -122.418307270836, 0.0, -122.0],
+        }
+    ).with_columns(inside=plh3.cell_contains_latlng("cell", "lat", "lng"))
+    assert df["inside"].to_list() == [True, True, False, None]
+
+
+def test_cell_contains_latlng_parent_and_scalar_point():
+    df = pl.DataFrame({"cell": [586265647244115967]}).with_columns(
+        parent=plh3.cell_to_parent("cell", 1)
+    )
+    point = df.select(
+        lat=plh3.cell_to_lat("cell"), lng=plh3.cell_to_lng("cell")
+    ).row(0)
+    df = df.with_columns(
+        in_cell=plh3.cell_contains_latlng("cell", *point),
+        in_parent=plh3.cell_contains_latlng("parent", *point),
+        elsewhere=plh3.cell_contains_latlng("cell", 0.0, 0.0),
+    )
+    assert df.select("in_cell", "in_parent", "elsewhere").row(0) == (True, True, False)
+
+
+def test_cell_contains_latlng_radians():
+    df = pl.DataFrame(
+        {
+            "cell": ["8928308280fffff", "8928308280fffff"],
+            "lat": [math.radians(37.7752702151959), 0.0],
+            "lng": [math.radians(-122.418307270836), 0.0],
+        }
+    ).with_columns(inside=plh3.cell_contains_latlng("cell", "lat", "lng", unit="rad"))
+    assert df["inside"].to_list() == [True, False]
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(plh3.cell_contains_latlng("cell", "lat", "lng", unit="grad"))  # type: ignore[arg-type]
+
+
+def test_cells_to_multi_polygon_wkt_single_cell():
+    df = pl.DataFrame({"cell": ["8a1fb46622dffff"]}).select(
+        wkt=plh3.cells_to_multi_polygon_wkt_agg("cell")
+    )
+    wkt = df["wkt"][0]
+    assert wkt.startswith("MULTIPOLYGON(((")
+    # closed ring of 6 vertices, in lng/lat order; `dissolve` may start the ring
+    # at any vertex
+    coords = wkt[len("MULTIPOLYGON(((") : -len(")))")].split(",")
+    assert len(coords) == 7
+    assert coords[0] == coords[-1]
+    vertices = [tuple(map(float, coord.split(" "))) for coord in coords]
+    assert any(
+        pytest.approx(vertex, abs=1e-7) == (2.372742411267222, 48.85456938453284)
+        for vertex in vertices
+    )
+
+
+def test_cells_to_multi_polygon_wkt_dissolves_and_holes():
+    ring = (
+        pl.DataFrame({"cell": ["8a1fb46622dffff"]})
+        .select(plh3.grid_ring("cell", 1))
+        .explode("cell")
+    )
+    df = ring.select(wkt=plh3.cells_to_multi_polygon_wkt_agg("cell"))
+    wkt = df["wkt"][0]
+    # one polygon made of an outer ring and a hole where the center cell is
+    assert wkt.count("((") == 1
+    assert wkt.count("),(") == 1
+
+
+def test_cells_to_multi_polygon_wkt_list_column():
+    df = pl.DataFrame(
+        {
+            "cells": [
+                [599686042433355775, 599686030622195711, 599686042433355775],
+                None,
+                [],
+            ]
+        },
+        schema={"cells": pl.List(pl.UInt64)},
+    ).with_columns(wkt=plh3.cells_to_multi_polygon_wkt("cells"))
+    wkts = df["wkt"].to_list()
+    assert wkts[0].startswith("MULTIPOLYGON(((")
+    assert wkts[0].count("((") == 1
+    assert wkts[1] is None
+    assert wkts[2] == "MULTIPOLYGON EMPTY"
+
+
+def test_cells_to_multi_polygon_wkt_with_columns():
+    df = pl.DataFrame(
+        {
+            "cell": ["8928308280fffff", "8928308280bffff", "8a1fb46622dffff"],
+            "res": [9, 9, 10],
+        }
+    )
+    # the List form keeps one value per row
+    per_row = df.with_columns(cells=pl.concat_list("cell")).with_columns(
+        wkt=plh3.cells_to_multi_polygon_wkt("cells")
+    )
+    assert per_row.height == 3
+    assert [wkt[:19] for wkt in per_row["wkt"]] == [
+        "MULTIPOLYGON(((-122",
+        "MULTIPOLYGON(((-122",
+        "MULTIPOLYGON(((2.37",
+    ]
+
+    # the aggregate is broadcast across the rows it was computed over
+    whole = df.filter(pl.col("res") == 9).with_columns(
+        wkt=plh3.cells_to_multi_polygon_wkt_agg("cell")
+    )
+    assert whole["wkt"].n_unique() == 1
+    assert whole["wkt"][0].count("((") == 1
+
+    per_group = df.with_columns(
+        wkt=plh3.cells_to_multi_polygon_wkt_agg("cell").over("res")
+    )
+    assert per_group["wkt"][0] == per_group["wkt"][1] == whole["wkt"][0]
+    assert per_group["wkt"][2].startswith("MULTIPOLYGON(((2.37")
+
+
+def test_cells_to_multi_polygon_wkt_wrong_shape():
+    df = pl.DataFrame({"cell": ["8928308280fffff"]})
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(plh3.cells_to_multi_polygon_wkt("cell"))
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(plh3.cells_to_multi_polygon_wkt_agg(pl.concat_list("cell")))
+
+
+def test_cells_to_multi_polygon_wkt_group_by():
+    df = (
+        pl.DataFrame(
+            {
+                "region": ["a", "a", "b"],
+                "cell": ["8928308280fffff", "8928308280bffff", "8a1fb46622dffff"],
+            }
+        )
+        .group_by("region")
+        .agg(wkt=plh3.cells_to_multi_polygon_wkt_agg("cell"))
+        .sort("region")
+    )
+    # one string per group, not a single-element list
+    assert df.schema["wkt"] == pl.Utf8
+    assert df["wkt"][0].startswith("MULTIPOLYGON(((-122.")
+    assert df["wkt"][1].startswith("MULTIPOLYGON(((2.")
+
+
+def test_cells_to_multi_polygon_wkt_mixed_resolutions():
+    df = pl.DataFrame({"cell": ["8928308280fffff", "8a1fb46622dffff"]})
+    wkt = df.select(wkt=plh3.cells_to_multi_polygon_wkt_agg("cell"))["wkt"]
+    assert wkt.to_list() == [None]
+
+    # only the mixed set is null, the other rows and groups still dissolve
+    df = pl.DataFrame(
+        {
+            "region": ["a", "a", "b"],
+            "cell": ["8928308280fffff", "8a1fb46622dffff", "8a1fb46622dffff"],
+        }
+    )
+    per_row = (
+        df.group_by("region", maintain_order=True)
+        .agg("cell")
+        .with_columns(wkt=plh3.cells_to_multi_polygon_wkt("cell"))
+    )
+    per_group = df.group_by("region", maintain_order=True).agg(
+        wkt=plh3.cells_to_multi_polygon_wkt_agg("cell")
+    )
+    for result in (per_row, per_group):
+        assert result["wkt"][0] is None
+        assert result["wkt"][1].startswith("MULTIPOLYGON(((")
+
+
+def test_cell_to_boundary_wkt():
+    df = pl.DataFrame(
+        {"cell": ["8a1fb46622dffff", None, "invalid"]}, schema={"cell": pl.Utf8}
+    ).with_columns(
+        wkt=plh3.cell_to_boundary_wkt("cell"),
+        boundary=plh3.cell_to_boundary("cell"),
+    )
+    wkt = df["wkt"][0]
+    assert wkt.startswith("POLYGON((")
+    coords = [
+        tuple(map(float, pair.split(" ")))
+        for pair in wkt[len("POLYGON((") : -len("))")].split(",")
+    ]
+    # closed ring, lng/lat order matching the lat/lng list output
+    assert len(coords) == 7
+    assert coords[0] == coords[-1]
+    for (lng, lat), (exp_lat, exp_lng) in zip(coords, df["boundary"][0].to_list()):
+        assert pytest.approx(lat, abs=1e-9) == exp_lat
+        assert pytest.approx(lng, abs=1e-9) == exp_lng
+    assert df["wkt"][1] is None
+    assert df["wkt"][2] is None
+
+
+def test_cell_to_boundary_wkb():
+    df = pl.DataFrame(
+        {"cell": ["8a1fb46622dffff", None]}, schema={"cell": pl.Utf8}
+    ).with_columns(
+        wkb=plh3.cell_to_boundary_wkb("cell"),
+        boundary=plh3.cell_to_boundary("cell"),
+    )
+    assert df["wkb"].dtype == pl.Binary
+    wkb = df["wkb"][0]
+    byte_order, geom_type, num_rings, num_points = struct.unpack("<BIII", wkb[:13])
+    assert (byte_order, geom_type, num_rings, num_points) == (1, 3, 1, 7)
+    assert len(wkb) == 13 + 16 * num_points
+    coords = [
+        struct.unpack("<dd", wkb[13 + 16 * i : 29 + 16 * i]) for i in range(num_points)
+    ]
+    assert coords[0] == coords[-1]
+    for (lng, lat), (exp_lat, exp_lng) in zip(coords, df["boundary"][0].to_list()):
+        assert pytest.approx(lat, abs=1e-9) == exp_lat
+        assert pytest.approx(lng, abs=1e-9) == exp_lng
+    assert df["wkb"][1] is None
+
+
+def test_cells_to_geojson():
+    df = pl.DataFrame(
+        {
+            "cell": ["892830828cfffff", None, "invalid"],
+            "count": [10, 20, 30],
+            "name": ["a", "b", "c"],
+            "score": [1.5, None, float("nan")],
+        }
+    )
+    geojson = json.loads(
+        df.select(plh3.cells_to_geojson("cell", "count", "name", "score")).item()
+    )
+    assert geojson["type"] == "FeatureCollection"
+    assert len(geojson["features"]) == 1
+
+    feature = geojson["features"][0]
+    assert feature["type"] == "Feature"
+    assert feature["id"] == "892830828cfffff"
+    assert feature["properties"] == {"count": 10, "name": "a", "score": 1.5}
+    assert feature["geometry"]["type"] == "Polygon"
+    ring = feature["geometry"]["coordinates"][0]
+    assert len(ring) == 7
+    assert ring[0] == ring[-1]
+    assert pytest.approx(ring[0], abs=1e-9) == [-122.40983065889338, 37.769357015488296]
+
+
+def test_cells_to_geojson_group_by():
+    df = (
+        pl.DataFrame(
+            {
+                "region": ["a", "a", "b"],
+                "cell": ["8928308280fffff", "8928308280bffff", "8a1fb46622dffff"],
+            }
+        )
+        .group_by("region")
+        .agg(geojson=plh3.cells_to_geojson("cell"))
+        .sort("region")
+    )
+    # one string per group, not a single-element list
+    assert df.schema["geojson"] == pl.Utf8
+    features = [len(json.loads(geojson)["features"]) for geojson in df["geojson"]]
+    assert features == [2, 1]
+
+
+def test_cells_to_geojson_antimeridian():
+    df = pl.DataFrame({"cell": ["825ba7fffffffff"]})
+    geojson = json.loads(df.select(plh3.cells_to_geojson("cell")).item())
+    geometry = geojson["features"][0]["geometry"]
+    assert geometry["type"] == "MultiPolygon"
+    assert len(geometry["coordinates"]) == 2
+    for polygon in geometry["coordinates"]:
+        ring = polygon[0]
+        assert ring[0] == ring[-1]
+        lngs = [lng for lng, _ in ring]
+        # each part stays on one side of the antimeridian
+        assert all(lng <= -178 for lng in lngs) or all(lng >= 178 for lng in lngs)
+
+
+def test_radians():
+    lat, lng = 37.7752702151959, -122.418307270836
+    df = pl.DataFrame({"lat": [math.radians(lat)], "lng": [math.radians(lng)]})
+    df = df.with_columns(
+        cell=plh3.latlng_to_cell("lat", "lng", 9, return_dtype=pl.Utf8, unit="rad")
+    ).with_columns(
+        lat_deg=plh3.cell_to_lat("cell"),
+        lat_rad=plh3.cell_to_lat("cell", unit="rad"),
+        lng_rad=plh3.cell_to_lng("cell", unit="rad"),
+        latlng_rad=plh3.cell_to_latlng("cell", unit="rad"),
+        struct_rad=plh3.cell_to_latlng("cell", output="struct", unit="rad"),
+        boundary_deg=plh3.cell_to_boundary("cell"),
+        boundary_rad=plh3.cell_to_boundary("cell", unit="rad"),
+    )
+    assert df["cell"][0] == "8928308280fffff"
+    assert pytest.approx(df["lat_rad"][0]) == math.radians(df["lat_deg"][0])
+    assert df["latlng_rad"][0].to_list() == [df["lat_rad"][0], df["lng_rad"][0]]
+    assert df["struct_rad"][0] == {"lat": df["lat_rad"][0], "lng": df["lng_rad"][0]}
+    for deg, rad in zip(
+        df["boundary_deg"][0].to_list(), df["boundary_rad"][0].to_list(), strict=True
+    ):
+        assert pytest.approx(rad) == [math.radians(v) for v in deg]
+
+    point = df.select(
+        plh3.point_to_cell(
+            plh3.cell_to_latlng("cell", output="geoarrow", unit="rad"),
+            9,
+            return_dtype=pl.Utf8,
+            unit="rad",
+        )
+    ).item()
+    assert point == "8928308280fffff"
+
+
+def test_invalid_unit():
+    df = pl.DataFrame({"cell": ["8928308280fffff"]})
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(plh3.cell_to_lat("cell", unit="grad"))  # type: ignore[arg-type]
+
+
+def test_cell_to_boundary_antimeridian():
+    df = pl.DataFrame({"cell": ["825ba7fffffffff", "8a1fb46622dffff"]}).with_columns(
+        ignore=plh3.cell_to_boundary("cell"),
+        split=plh3.cell_to_boundary("cell", antimeridian="split"),
+        unwrap=plh3.cell_to_boundary("cell", antimeridian="unwrap"),
+        wkt=plh3.cell_to_boundary_wkt("cell", antimeridian="split"),
+        wkb=plh3.cell_to_boundary_wkb("cell", antimeridian="split"),
+        geoarrow=plh3.cell_to_boundary("cell", output="geoarrow", antimeridian="split"),
+    )
+    assert df["split"].dtype == pl.List(pl.List(pl.List(pl.Float64)))
+
+    ignored = [lng for _, lng in df["ignore"][0].to_list()]
+    assert max(ignored) - min(ignored) > 180
+
+    unwrapped = [lng for _, lng in df["unwrap"][0].to_list()]
+    assert max(unwrapped) - min(unwrapped) < 5
+    assert min(unwrapped) < -180
+
+    parts = df["split"][0].to_list()
+    assert len(parts) == 2
+    for part in parts:
+        lngs = [lng for _, lng in part]
+        assert all(lng <= -178 for lng in lngs) or all(lng >= 178 for lng in lngs)
+
+    assert df["wkt"][0].startswith("MULTIPOLYGON(")
+    assert struct.unpack("<BII", df["wkb"][0][:9]) == (1, 6, 2)
+    assert len(df["geoarrow"][0]) == 2
+
+    # cells away from the antimeridian are unaffected
+    assert df["split"][1].to_list() == [df["ignore"][1].to_list()]
+    assert df["unwrap"][1].to_list() == df["ignore"][1].to_list()
+    assert df["wkt"][1].startswith("POLYGON(")
+
+
+def test_write_geojson(tmp_path):
+    df = pl.DataFrame({"cell": [599686042433355775], "value": [True]})
+    path = tmp_path / "cells.geojson"
+    geojson = plh3.graphing.write_geojson(df, hex_id_col="cell", path=path)
+    assert path.read_text() == geojson
+    feature = json.loads(geojson)["features"][0]
+    assert feature["id"] == "85283473fffffff"
+    assert feature["properties"] == {"value": True}
+
+
+def test_cell_to_latlng_geoarrow():
+    df = pl.DataFrame(
+        {"cell": ["85283473fffffff", None, "invalid"]}, schema={"cell": pl.Utf8}
+    ).with_columns(
+        point=plh3.cell_to_latlng("cell", output="geoarrow"),
+        latlng=plh3.cell_to_latlng("cell"),
+    )
+    assert df["point"].dtype == pl.Struct({"x": pl.Float64, "y": pl.Float64})
+    point = df["point"][0]
+    lat, lng = df["latlng"][0].to_list()
+    assert point == {"x": lng, "y": lat}
+    assert df["point"].is_null().to_list() == [False, True, True]
+
+
+def test_cell_to_latlng_struct():
+    df = pl.DataFrame(
+        {"cell": ["85283473fffffff", None, "invalid"]}, schema={"cell": pl.Utf8}
+    ).with_columns(
+        coords=plh3.cell_to_latlng("cell", output="struct"),
+        latlng=plh3.cell_to_latlng("cell"),
+    )
+    assert df["coords"].dtype == pl.Struct({"lat": pl.Float64, "lng": pl.Float64})
+    lat, lng = df["latlng"][0].to_list()
+    assert df["coords"][0] == {"lat": lat, "lng": lng}
+    assert df["coords"].is_null().to_list() == [False, True, True]
+    assert df["latlng"].is_null().to_list() == [False, True, True]
+
+    unnested = df.select(pl.col("coords").struct.unnest())
+    assert unnested.columns == ["lat", "lng"]
+
+
+def test_invalid_coordinate_output():
+    with pytest.raises(ValueError):
+        plh3.cell_to_latlng("cell", output="wkt")  # type: ignore[arg-type]
+
+    with pytest.raises(ValueError):
+        plh3.cell_to_boundary("cell", output="struct")  # type: ignore[arg-type]
+
+
+def test_cell_to_boundary_geoarrow():
+    df = pl.DataFrame(
+        {"cell": ["8a1fb46622dffff", None]}, schema={"cell": pl.Utf8}
+    ).with_columns(
+        polygon=plh3.cell_to_boundary("cell", output="geoarrow"),
+        boundary=plh3.cell_to_boundary("cell"),
+    )
+    point = pl.Struct({"x": pl.Float64, "y": pl.Float64})
+    assert df["polygon"].dtype == pl.List(pl.List(point))
+    rings = df["polygon"][0].to_list()
+    assert len(rings) == 1
+    ring = rings[0]
+    # closed ring
+    assert len(ring) == 7
+    assert ring[0] == ring[-1]
+    for vertex, (lat, lng) in zip(ring, df["boundary"][0].to_list()):
+        assert vertex == {"x": lng, "y": lat}
+    assert df["polygon"][1] is None
+
+
+def test_cell_to_latlng_invalid_output():
+    with pytest.raises(ValueError):
+        plh3.cell_to_latlng("cell", output="wkt")
+
+
+def test_point_to_cell():
+    df = pl.DataFrame(
+        {
+            "point": [
+                {"x": -122.418307270836, "y": 37.7752702151959},
+                {"x": 0.0, "y": float("nan")},
+                None,
+            ]
+        }
+    ).with_columns(
+        cell_str=plh3.point_to_cell("point", 9, return_dtype=pl.Utf8),
+        cell_int=plh3.point_to_cell("point", 9),
+    )
+    assert df["cell_str"].to_list() == ["8928308280fffff", None, None]
+    assert df["cell_int"].to_list() == [int("8928308280fffff", 16), None, None]
+
+
+def test_point_to_cell_round_trip():
+    df = pl.DataFrame({"cell": ["8928308280fffff"]}).with_columns(
+        round_trip=plh3.point_to_cell(
+            plh3.cell_to_latlng("cell", output="geoarrow"), 9, return_dtype=pl.Utf8
+        )
+    )
+    assert df["round_trip"][0] == "8928308280fffff"
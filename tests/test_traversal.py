@@ -1,3 +1,5 @@
+import math
+
 import polars as pl
 import pytest
 
@@ -82,6 +84,82 @@ def test_grid_disk_raises_invalid_k():
         )
 
 
+def test_grid_disk_distances():
+    df = pl.DataFrame(
+        {"cell": ["8928308280fffff", None, "zz"]}, schema={"cell": pl.Utf8}
+    ).with_columns(disk=plh3.grid_disk_distances("cell", 1))
+    assert df["disk"].to_list() == [
+        [
+            {"cell": "8928308280fffff", "k": 0},
+            {"cell": "89283082803ffff", "k": 1},
+            {"cell": "89283082807ffff", "k": 1},
+            {"cell": "8928308280bffff", "k": 1},
+            {"cell": "8928308283bffff", "k": 1},
+            {"cell": "89283082873ffff", "k": 1},
+            {"cell": "89283082877ffff", "k": 1},
+        ],
+        None,
+        None,
+    ]
+
+
+def test_grid_disk_distances_matches_grid_distance():
+    df = (
+        pl.DataFrame({"origin": [622054503267303423]}, schema={"origin": pl.UInt64})
+        .with_columns(disk=plh3.grid_disk_distances("origin", 3))
+        .explode("disk")
+        .unnest("disk")
+        .with_columns(distance=plh3.grid_distance("origin", "cell"))
+    )
+    assert df.height == 37
+    assert df["cell"].dtype == pl.UInt64
+    assert (df["k"].cast(pl.Int32) == df["distance"]).all()
+
+
+def test_grid_disk_distances_rings():
+    df = pl.DataFrame(
+        {
+            "cell": ["8928308280fffff", "8009fffffffffff", "8928308280fffff"],
+            "k": [2, 2, -1],
+        }
+    ).with_columns(
+        rings=plh3.grid_disk_distances("cell", "k", output="rings"),
+    )
+    sizes = df["rings"].list.eval(pl.element().list.len()).to_list()
+    # pentagons have five neighbors, and negative k returns None
+    assert sizes == [[1, 6, 12], [1, 5, 10], None]
+    assert df["rings"].to_list()[0][0] == ["8928308280fffff"]
+
+
+def test_grid_disk_distances_rings_past_grid_edge():
+    # every resolution 0 cell is within 10 steps of this pentagon
+    df = pl.select(
+        rings=plh3.grid_disk_distances(pl.lit("8009fffffffffff"), 12, output="rings")
+    )
+    sizes = df["rings"].list.eval(pl.element().list.len()).to_list()[0]
+    assert len(sizes) == 13
+    assert sum(sizes) == 122
+    assert sizes[-2:] == [0, 0]
+
+
+def test_grid_disk_distances_invalid_params():
+    with pytest.raises(ValueError):
+        pl.select(plh3.grid_disk_distances(pl.lit("8928308280fffff"), -1))
+    with pytest.raises(ValueError):
+        pl.select(
+            plh3.grid_disk_distances(pl.lit("8928308280fffff"), 1, output="list")  # type: ignore
+        )
+
+
+def test_grid_disk_distances_single_row_k_column():
+    df = pl.DataFrame({"cell": ["8928308280fffff"], "k": [-1]}).with_columns(
+        disk=plh3.grid_disk_distances("cell", "k"),
+        rings=plh3.grid_disk_distances("cell", "k", output="rings"),
+    )
+    assert df["disk"].to_list() == [None]
+    assert df["rings"].to_list() == [None]
+
+
 @pytest.mark.parametrize(
     "test_params",
     [
@@ -491,6 +569,73 @@ def test_local_ij_to_cell(test_params):
     assert df["cell"].to_list()[0] == test_params["output"]
 
 
+def test_grid_distance_matrix():
+    df = pl.DataFrame(
+        {
+            "cells": [
+                ["8928308280fffff", "8928308280bffff", "89283082813ffff", None],
+                None,
+                [],
+            ]
+        },
+        schema={"cells": pl.List(pl.Utf8)},
+    ).with_columns(matrix=plh3.grid_distance_matrix("cells"))
+    assert df["matrix"].to_list() == [
+        [[0, 1, 2, None], [1, 0, 2, None], [2, 2, 0, None], [None, None, None, None]],
+        None,
+        [],
+    ]
+
+
+def test_grid_distance_matrix_long():
+    df = pl.DataFrame(
+        {"cells": [[622054503267303423, 622054502770606079, 622054503267270655]]},
+        schema={"cells": pl.List(pl.UInt64)},
+    )
+    pairs = (
+        df.select(plh3.grid_distance_matrix("cells", output="long"))
+        .explode("cells")
+        .unnest("cells")
+    )
+    assert pairs.columns == ["origin", "destination", "distance"]
+    assert pairs["origin"].dtype == pl.UInt64
+    assert pairs.height == 3
+
+    expected = pairs.select(plh3.grid_distance("origin", "destination"))
+    assert pairs["distance"].to_list() == expected["origin"].to_list()
+
+    with pytest.raises(ValueError):
+        df.select(plh3.grid_distance_matrix("cells", output="wide"))  # type: ignore
+
+
+def test_grid_ring_pentagon():
+    df = pl.DataFrame(
+        {"cell": ["8009fffffffffff", "8001fffffffffff", "8928308280fffff"]}
+    ).with_columns(
+        ring=plh3.grid_ring("cell", 1),
+        ring_null=plh3.grid_ring("cell", 1, strict="null"),
+    )
+    # the pentagon and its neighbor both have full rings
+    assert df["ring"].list.len().to_list() == [5, 6, 6]
+    assert df["ring_null"].list.len().to_list() == [None, None, 6]
+
+    disk = df.select(
+        plh3.grid_disk_distances("cell", 1, output="rings").list.get(1).list.sort()
+    )["cell"]
+    assert df["ring"].list.sort().to_list() == disk.to_list()
+
+
+def test_grid_ring_strict_raise():
+    df = pl.DataFrame({"cell": ["8009fffffffffff"]})
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(plh3.grid_ring("cell", 1, strict="raise"))
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(plh3.grid_ring("cell", 1, strict="skip"))  # type: ignore
+    assert df.select(plh3.grid_ring("cell", 0, strict="raise"))["cell"].to_list() == [
+        ["8009fffffffffff"]
+    ]
+
+
 def test_grid_ring_and_disk_with_column_k():
     df = pl.DataFrame(
         {
@@ -547,3 +692,347 @@ def test_grid_ring_k_supplied_as_int():
         plh3.grid_ring("cell", 1).list.sort().alias("ring"),
         plh3.grid_disk("cell", 1).list.sort().alias("disk"),
     )
+
+
+def test_grid_ring_single_row_k_column():
+    # a negative k in a one-row column is a null row, not a bad literal
+    df = pl.DataFrame({"cell": ["8928308280fffff"], "k": [-1]})
+    assert df.select(plh3.grid_ring("cell", "k"))["cell"].to_list() == [None]
+
+
+def test_grid_path_avoiding():
+    df = pl.DataFrame(
+        {
+            "origin": ["8928308280fffff"] * 4,
+            "destination": [
+                "89283082813ffff",
+                "89283082813ffff",
+                "8928308280fffff",
+                "85283083fffffff",
+            ],
+            "blocked": [[], ["89283082803ffff"], [], []],
+        },
+        schema={
+            "origin": pl.Utf8,
+            "destination": pl.Utf8,
+            "blocked": pl.List(pl.Utf8),
+        },
+    ).with_columns(route=plh3.grid_path_avoiding("origin", "destination", "blocked"))
+    assert df["route"].to_list() == [
+        {
+            "path": ["8928308280fffff", "89283082803ffff", "89283082813ffff"],
+            "cost": 2.0,
+        },
+        {
+            "path": [
+                "8928308280fffff",
+                "89283082807ffff",
+                "89283082817ffff",
+                "89283082813ffff",
+            ],
+            "cost": 3.0,
+        },
+        {"path": ["8928308280fffff"], "cost": 0.0},
+        # different resolutions
+        None,
+    ]
+
+    # rows are independent, so running per group gives the same routes
+    per_group = df.with_columns(
+        route=plh3.grid_path_avoiding("origin", "destination", "blocked").over(
+            "destination"
+        )
+    )
+    assert per_group["route"].to_list() == df["route"].to_list()
+
+
+def test_grid_path_avoiding_costs():
+    df = pl.DataFrame(
+        {"origin": ["8928308280fffff"], "destination": ["89283082813ffff"]}
+    )
+    costs = pl.lit(
+        [{"cell": "89283082803ffff", "cost": 0.25}],
+        dtype=pl.List(pl.Struct({"cell": pl.Utf8, "cost": pl.Float64})),
+    )
+    blocked = pl.lit([], dtype=pl.List(pl.Utf8))
+    route = df.select(
+        plh3.grid_path_avoiding("origin", "destination", blocked, costs)
+    )["origin"]
+    assert route.struct.field("cost").to_list() == [1.25]
+
+    # a tiny search limit gives up
+    limited = df.select(
+        plh3.grid_path_avoiding("origin", "destination", blocked, max_cells=1)
+    )["origin"]
+    assert limited.to_list() == [None]
+
+    with pytest.raises(ValueError):
+        df.select(plh3.grid_path_avoiding("origin", "destination", blocked, max_cells=0))
+
+
+def test_grid_path_avoiding_near_pentagon():
+    # no grid distance can be computed across the pentagon, so the heuristic
+    # falls back to 0 for some cells and the search must re-expand them
+    df = pl.DataFrame(
+        {"origin": ["844c23dffffffff"], "destination": ["844c1c3ffffffff"]}
+    )
+    costs = pl.lit(
+        [
+            {"cell": "844c039ffffffff", "cost": 2.0},
+            {"cell": "844c03bffffffff", "cost": 3.0},
+            {"cell": "844c2a1ffffffff", "cost": 3.0},
+            {"cell": "844c04bffffffff", "cost": 3.0},
+            {"cell": "844c041ffffffff", "cost": 2.0},
+            {"cell": "844c2a5ffffffff", "cost": 3.0},
+            {"cell": "844c051ffffffff", "cost": 2.0},
+            {"cell": "844c055ffffffff", "cost": 3.0},
+            {"cell": "844c2abffffffff", "cost": 2.0},
+        ],
+        dtype=pl.List(pl.Struct({"cell": pl.Utf8, "cost": pl.Float64})),
+    )
+    blocked = pl.lit(["844c001ffffffff"], dtype=pl.List(pl.Utf8))
+    route = df.select(
+        plh3.grid_path_avoiding("origin", "destination", blocked, costs)
+    )["origin"]
+    assert route.struct.field("cost").to_list() == [11.0]
+
+
+def test_cells_connected_components():
+    df = pl.DataFrame(
+        {
+            "cell": [
+                "8928308280fffff",
+                "89283082813ffff",
+                "8928308280bffff",
+                None,
+                "8928308280fffff",
+                "85283083fffffff",
+            ]
+        }
+    ).with_columns(
+        component=plh3.cells_connected_components("cell"),
+        component_2=plh3.cells_connected_components("cell", order=2),
+    )
+    assert df["component"].to_list() == [0, 1, 0, None, 0, 2]
+    assert df["component_2"].to_list() == [0, 0, 0, None, 0, 1]
+
+
+def test_cells_connected_components_groups():
+    df = pl.DataFrame(
+        {
+            "group": ["a", "a", "b", "b"],
+            "cell": [
+                "8928308280fffff",
+                "89283082813ffff",
+                "8928308280fffff",
+                "8928308280bffff",
+            ],
+        }
+    )
+    over = df.with_columns(
+        component=plh3.cells_connected_components("cell").over("group")
+    )
+    assert over["component"].to_list() == [0, 1, 0, 0]
+
+    grouped = (
+        df.group_by("group", maintain_order=True)
+        .agg(cells=pl.col("cell"))
+        .with_columns(components=plh3.cells_connected_components("cells"))
+    )
+    assert grouped["components"].to_list() == [[0, 1], [0, 0]]
+
+    with pytest.raises(ValueError):
+        df.select(plh3.cells_connected_components("cell", order=0))
+
+
+def test_cells_dilate_and_erode():
+    center = "8928308280fffff"
+    df = pl.DataFrame({"cell": [center, None]}).with_columns(
+        cells=plh3.grid_disk("cell", 1),
+        disk_2=plh3.grid_disk("cell", 2),
+    )
+    df = df.with_columns(
+        dilated=plh3.cells_dilate("cells", 1),
+        eroded=plh3.cells_erode("cells", 1),
+        eroded_2=plh3.cells_erode("cells", 2),
+    )
+    assert sorted(df["dilated"][0].to_list()) == sorted(df["disk_2"][0].to_list())
+    # the set's own cells come first
+    assert df["dilated"][0].to_list()[:7] == df["cells"][0].to_list()
+    assert df["eroded"].to_list() == [[center], None]
+    assert df["eroded_2"].to_list() == [[], None]
+
+
+def test_cells_dilate_and_erode_options():
+    children = [
+        "89283082803ffff",
+        "89283082807ffff",
+        "8928308280bffff",
+        "8928308280fffff",
+        "89283082813ffff",
+        "89283082817ffff",
+        "8928308281bffff",
+    ]
+    df = pl.DataFrame(
+        {"cells": [children, children + children], "k": [0, -1]},
+    ).with_columns(
+        dilated=plh3.cells_dilate("cells", 0, compact=True),
+        eroded=plh3.cells_erode("cells", "k"),
+    )
+    assert df["dilated"].to_list() == [["8828308281fffff"], ["8828308281fffff"]]
+    assert df["eroded"].to_list() == [children, None]
+
+    with pytest.raises(ValueError):
+        df.select(plh3.cells_dilate("cells", -1))
+
+
+def test_kring_smooth():
+    df = pl.DataFrame(
+        {
+            "cell": [
+                "8928308280fffff",
+                "8928308280bffff",
+                "89283082813ffff",
+                "8928308280fffff",
+                None,
+            ],
+            "value": [10, 4, 1, None, 3],
+        }
+    ).with_columns(
+        uniform=plh3.kring_smooth("cell", "value", 1),
+        inverse=plh3.kring_smooth("cell", "value", 1, kernel="inverse_distance"),
+        gaussian=plh3.kring_smooth("cell", "value", 1, kernel="gaussian"),
+        own=plh3.kring_smooth("cell", "value", 0),
+    )
+    assert df["uniform"].to_list() == [7.0, 7.0, 1.0, 7.0, None]
+    assert df["inverse"].to_list() == [8.0, 6.0, 1.0, 8.0, None]
+    assert df["own"].to_list() == [10.0, 4.0, 1.0, 10.0, None]
+    weight = math.exp(-0.5)
+    assert df["gaussian"][0] == pytest.approx((10 + 4 * weight) / (1 + weight))
+
+
+def test_kring_smooth_groups():
+    df = pl.DataFrame(
+        {
+            "group": ["a", "a", "b"],
+            "cell": ["8928308280fffff", "8928308280bffff", "8928308280fffff"],
+            "value": [10.0, 4.0, 1.0],
+        }
+    ).with_columns(smoothed=plh3.kring_smooth("cell", "value", 1).over("group"))
+    assert df["smoothed"].to_list() == [7.0, 7.0, 1.0]
+
+    with pytest.raises(ValueError):
+        df.select(plh3.kring_smooth("cell", "value", -1))
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(plh3.kring_smooth("cell", "value", 1, kernel="gaussian", sigma=0))
+
+
+def test_getis_ord_gi_star():
+    df = pl.DataFrame(
+        {
+            "cell": [
+                "8928308280fffff",
+                "8928308280bffff",
+                "89283082873ffff",
+                "89283082813ffff",
+                "8928308281bffff",
+                "89283082857ffff",
+                None,
+            ],
+            "value": [10, 9, 8, 1, 2, None, 5],
+        }
+    ).with_columns(gi=plh3.getis_ord_gi_star("cell", "value", 1))
+    gi = df["gi"].to_list()
+    assert gi[6] is None
+    z_scores = [row["z_score"] for row in gi[:5]]
+    p_values = [row["p_value"] for row in gi[:5]]
+    assert z_scores == pytest.approx(
+        [1.963961, 1.336306, 1.963961, -1.963961, -1.309307]
+    )
+    assert p_values == pytest.approx(
+        [0.049535, 0.181449, 0.049535, 0.049535, 0.190430], abs=1e-6
+    )
+    # a cell without a value still gets a score from its neighbours
+    assert gi[5]["z_score"] == pytest.approx(-0.218218)
+
+
+def test_getis_ord_gi_star_undefined():
+    df = pl.DataFrame(
+        {"cell": ["8928308280fffff", "8928308280bffff"], "value": [3.0, 3.0]}
+    ).with_columns(gi=plh3.getis_ord_gi_star("cell", "value", 1))
+    # no spread in a constant column
+    assert df["gi"].to_list() == [None, None]
+    with pytest.raises(ValueError):
+        df.select(plh3.getis_ord_gi_star("cell", "value", -1))
+
+
+ROUTE_CELLS = [
+    "89283082803ffff",
+    "8928308280bffff",
+    "89283082847ffff",
+    "8928308287bffff",
+    "89283082ab7ffff",
+    "89283082aa3ffff",
+]
+
+
+def test_linestring_to_cells_wkt():
+    df = pl.DataFrame(
+        {
+            "route": [
+                "LINESTRING(-122.418 37.775, -122.405 37.785)",
+                # going back along the same route doesn't repeat cells
+                "LINESTRING(-122.418 37.775, -122.405 37.785, -122.418 37.775)",
+                "MULTILINESTRING((-122.418 37.775, -122.405 37.785), (0 0, 0.01 0.01))",
+                "LINESTRING(-122.418 37.775)",
+                "POINT(-122.418 37.775)",
+                "not wkt",
+                None,
+            ]
+        }
+    ).with_columns(cells=plh3.linestring_to_cells("route", 9, return_dtype=pl.Utf8))
+    cells = df["cells"].to_list()
+    assert cells[0] == ROUTE_CELLS
+    assert cells[1] == ROUTE_CELLS
+    assert cells[2][:6] == ROUTE_CELLS
+    assert len(cells[2]) == 13
+    assert cells[3] == ["89283082803ffff"]
+    assert cells[4:] == [None, None, None]
+
+
+def test_linestring_to_cells_coordinate_list():
+    df = pl.DataFrame(
+        {"route": [[[37.775, -122.418], [37.785, -122.405]], [[37.775]], None]},
+        schema={"route": pl.List(pl.List(pl.Float64))},
+    ).with_columns(cells=plh3.linestring_to_cells("route", 9))
+    assert df["cells"].dtype == pl.List(pl.UInt64)
+    assert df["cells"].to_list() == [
+        [int(cell, 16) for cell in ROUTE_CELLS],
+        None,
+        None,
+    ]
+
+
+def test_linestring_to_cells_radians():
+    route = [[37.775, -122.418], [37.785, -122.405]]
+    coords = pl.List(pl.List(pl.Float64))
+    routes = pl.DataFrame(
+        {
+            "deg": [route],
+            "rad": [[[math.radians(v) for v in pair] for pair in route]],
+        },
+        schema={"deg": coords, "rad": coords},
+    )
+    df = routes.select(
+        deg=plh3.linestring_to_cells("deg", 9, return_dtype=pl.Utf8),
+        rad=plh3.linestring_to_cells("rad", 9, return_dtype=pl.Utf8, unit="rad"),
+    )
+    assert df["deg"].to_list() == [ROUTE_CELLS]
+    assert df["rad"].to_list() == df["deg"].to_list()
+    with pytest.raises(pl.exceptions.ComputeError):
+        routes.select(plh3.linestring_to_cells("deg", 9, unit="grad"))  # type: ignore[arg-type]
+
+
+def test_linestring_to_cells_invalid_resolution():
+    with pytest.raises(ValueError):
+        plh3.linestring_to_cells("route", 16)
@@ -1,3 +1,5 @@
+import math
+import struct
 from typing import Union
 
 import polars as pl
@@ -126,3 +128,56 @@ def test_vertex_to_latlng(vertex, schema, expected_coords):
         lat, lng = result
         assert pytest.approx(lat, 0.000001) == expected_coords[0]
         assert pytest.approx(lng, 0.000001) == expected_coords[1]
+
+
+def test_vertex_to_latlng_struct():
+    df = pl.DataFrame(
+        {"vertex": [2459626752788398079, None]}, schema={"vertex": pl.UInt64}
+    ).with_columns(
+        coords=plh3.vertex_to_latlng("vertex", output="struct"),
+        point=plh3.vertex_to_latlng("vertex", output="geoarrow"),
+    )
+    assert df["coords"].dtype == pl.Struct({"lat": pl.Float64, "lng": pl.Float64})
+    coords = df["coords"][0]
+    assert pytest.approx(coords["lat"], 0.000001) == 39.38084284181812
+    assert pytest.approx(coords["lng"], 0.000001) == 88.57496213785487
+    assert df["point"][0] == {"x": coords["lng"], "y": coords["lat"]}
+    assert df["coords"].is_null().to_list() == [False, True]
+
+
+@pytest.mark.parametrize(
+    "vertex, schema, expected_coords",
+    [
+        (["2222597fffffffff"], None, [39.38084284181813, 88.57496213785487]),
+        (
+            [2459626752788398079],
+            {"vertex": pl.UInt64},
+            [39.38084284181812, 88.57496213785487],
+        ),
+        (["823d6ffffffffff"], None, None),  # Invalid vertex, expect None
+    ],
+)
+def test_vertex_to_latlng_wkb(vertex, schema, expected_coords):
+    df = pl.DataFrame({"vertex": vertex}, schema=schema).with_columns(
+        point=plh3.vertex_to_latlng_wkb("vertex")
+    )
+
+    result = df["point"].to_list()[0]
+    if expected_coords is None:
+        assert result is None
+    else:
+        byte_order, geom_type, lng, lat = struct.unpack("<BIdd", result)
+        assert (byte_order, geom_type) == (1, 1)
+        assert pytest.approx(lat, 0.000001) == expected_coords[0]
+        assert pytest.approx(lng, 0.000001) == expected_coords[1]
+
+
+def test_vertex_to_latlng_radians():
+    df = pl.DataFrame(
+        {"vertex": [2459626752788398079]}, schema={"vertex": pl.UInt64}
+    ).with_columns(
+        deg=plh3.vertex_to_latlng("vertex"),
+        rad=plh3.vertex_to_latlng("vertex", unit="rad"),
+    )
+    lat, lng = df["deg"][0].to_list()
+    assert df["rad"][0].to_list() == pytest.approx([math.radians(lat), math.radians(lng)])